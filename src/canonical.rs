@@ -0,0 +1,209 @@
+//! Canonical text representation of [`FluentArgs`].
+//!
+//! The canonical form lists one argument per line, sorted by name:
+//!
+//! ```text
+//! count = 3
+//! name = "Alice \"Al\" Smith"
+//! nickname = none
+//! ```
+//!
+//! - Names that are valid Fluent identifiers are written as is. Other names are
+//!   quoted.
+//! - Strings are quoted, with `\\`, `\"`, `\n`, `\r`, `\t` and `\u{...}` escapes.
+//! - Numbers are written in their shortest round-trip decimal form. Formatting
+//!   options are not part of the canonical form.
+//! - [`FluentValue::None`] and [`FluentValue::Error`] are written as `none` and
+//!   `error`. Custom values are not supported.
+//!
+//! Blank lines and lines starting with `#` are ignored when parsing.
+
+use std::fmt::Write as _;
+
+use fluent::types::{FluentNumber, FluentNumberOptions};
+use fluent::{FluentArgs, FluentValue};
+use thiserror::Error;
+
+/// Canonical text format error.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("custom value of `{0}` has no canonical representation")]
+    UnsupportedValue(String),
+    #[error("line {line}: {message}")]
+    Parse { line: usize, message: String },
+}
+
+/// Writes `args` in the canonical text format.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentArgs;
+/// use fluent_serde::canonical::{from_canonical_str, to_canonical_string};
+///
+/// let mut args = FluentArgs::new();
+/// args.set("name", "Alice");
+/// args.set("count", 3);
+///
+/// let text = to_canonical_string(&args).unwrap();
+/// assert_eq!("count = 3\nname = \"Alice\"\n", text);
+///
+/// let parsed = from_canonical_str(&text).unwrap();
+/// assert_eq!(args.get("name"), parsed.get("name"));
+/// assert_eq!(args.get("count"), parsed.get("count"));
+/// ```
+pub fn to_canonical_string(args: &FluentArgs) -> Result<String, Error> {
+    let mut entries: Vec<_> = args.iter().collect();
+    entries.sort_by_key(|(key, _)| *key);
+
+    let mut out = String::new();
+    for (key, value) in entries {
+        if is_bare(key) {
+            out.push_str(key);
+        } else {
+            write_quoted(&mut out, key);
+        }
+        out.push_str(" = ");
+
+        match value {
+            FluentValue::String(s) => write_quoted(&mut out, s),
+            FluentValue::Number(n) => write!(out, "{}", n.value).unwrap(),
+            FluentValue::None => out.push_str("none"),
+            FluentValue::Error => out.push_str("error"),
+            FluentValue::Custom(_) => return Err(Error::UnsupportedValue(key.to_string())),
+        }
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+/// Parses arguments from the canonical text format. Duplicate names are rejected.
+pub fn from_canonical_str(s: &str) -> Result<FluentArgs<'static>, Error> {
+    let mut args = FluentArgs::new();
+
+    for (index, line) in s.lines().enumerate() {
+        let line_no = index + 1;
+        let err = |message: &str| Error::Parse {
+            line: line_no,
+            message: message.to_string(),
+        };
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, rest) = if line.starts_with('"') {
+            parse_quoted(line).map_err(&err)?
+        } else {
+            let end = line
+                .find(|c: char| c.is_whitespace() || c == '=')
+                .unwrap_or(line.len());
+            (line[..end].to_string(), &line[end..])
+        };
+        if key.is_empty() {
+            return Err(err("expected an argument name"));
+        }
+
+        let rest = rest.trim_start();
+        let rest = rest
+            .strip_prefix('=')
+            .ok_or_else(|| err("expected `=` after the argument name"))?
+            .trim_start();
+
+        let value = if rest.starts_with('"') {
+            let (value, trailing) = parse_quoted(rest).map_err(&err)?;
+            if !trailing.trim().is_empty() {
+                return Err(err("unexpected characters after the string"));
+            }
+            FluentValue::String(value.into())
+        } else {
+            match rest.trim_end() {
+                "none" => FluentValue::None,
+                "error" => FluentValue::Error,
+                number => {
+                    let value = number
+                        .parse::<f64>()
+                        .map_err(|_| err("expected a string, a number, `none`, or `error`"))?;
+                    FluentValue::Number(FluentNumber::new(value, FluentNumberOptions::default()))
+                }
+            }
+        };
+
+        if args.get(key.as_str()).is_some() {
+            return Err(err("duplicate argument name"));
+        }
+        args.set(key, value);
+    }
+
+    Ok(args)
+}
+
+fn is_bare(key: &str) -> bool {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn write_quoted(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{{{:x}}}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Parses a quoted string at the start of `s`, returning it along with the rest of
+/// the input.
+fn parse_quoted(s: &str) -> Result<(String, &str), &'static str> {
+    let mut out = String::new();
+    let mut chars = s.char_indices().skip(1);
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((out, &s[i + 1..])),
+            '\\' => match chars.next().map(|(_, c)| c) {
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                Some('n') => out.push('\n'),
+                Some('r') => out.push('\r'),
+                Some('t') => out.push('\t'),
+                Some('u') => {
+                    if chars.next().map(|(_, c)| c) != Some('{') {
+                        return Err("expected `{` after `\\u`");
+                    }
+                    let mut hex = String::new();
+                    loop {
+                        match chars.next().map(|(_, c)| c) {
+                            Some('}') => break,
+                            Some(c) => hex.push(c),
+                            None => return Err("unterminated unicode escape"),
+                        }
+                    }
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or("invalid unicode escape")?;
+                    out.push(c);
+                }
+                _ => return Err("invalid escape sequence"),
+            },
+            c => out.push(c),
+        }
+    }
+
+    Err("unterminated string")
+}
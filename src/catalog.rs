@@ -0,0 +1,129 @@
+//! Coverage checking of message identifiers against Fluent resources.
+
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
+use std::iter::FromIterator;
+
+use fluent::FluentResource;
+use fluent_syntax::ast;
+
+/// Catalog of the message identifiers used by a program.
+///
+/// With the `codegen` feature, the generated `MESSAGE_IDS` constant lists the
+/// identifiers of all generated structs.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentResource;
+/// use fluent_serde::catalog::Catalog;
+///
+/// let catalog = Catalog::new(["hello", "cart"]);
+/// let resource = FluentResource::try_new("hello = Hello!\nbye = Goodbye!".into()).unwrap();
+///
+/// let report = catalog.coverage(&[resource]);
+/// assert_eq!(vec!["cart"], report.missing);
+/// assert_eq!(vec!["bye"], report.unreferenced);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Catalog {
+    ids: BTreeSet<String>,
+}
+
+/// Differences between a [`Catalog`] and the messages in a set of resources.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// Identifiers in the catalog that no resource defines.
+    pub missing: Vec<String>,
+    /// Messages defined in the resources that are not in the catalog.
+    pub unreferenced: Vec<String>,
+}
+
+impl CoverageReport {
+    /// Returns `true` if the catalog and the resources define the same messages.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.unreferenced.is_empty()
+    }
+}
+
+impl Catalog {
+    /// Creates a [`Catalog`] of `ids`.
+    pub fn new<I>(ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        ids.into_iter().collect()
+    }
+
+    /// Adds `id` to the catalog. Returns `false` if it was already present.
+    pub fn insert(&mut self, id: impl Into<String>) -> bool {
+        self.ids.insert(id.into())
+    }
+
+    /// Returns `true` if the catalog contains `id`.
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Returns an iterator over the identifiers in the catalog, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.ids.iter().map(String::as_str)
+    }
+
+    /// Compares the catalog with the messages defined in `resources`.
+    pub fn coverage<I>(&self, resources: I) -> CoverageReport
+    where
+        I: IntoIterator,
+        I::Item: Borrow<FluentResource>,
+    {
+        let resources = resources.into_iter().collect::<Vec<_>>();
+        let defined = resources
+            .iter()
+            .flat_map(|resource| resource.borrow().entries())
+            .filter_map(|entry| match entry {
+                ast::Entry::Message(message) => Some(message.id.name),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+
+        CoverageReport {
+            missing: self
+                .iter()
+                .filter(|id| !defined.contains(id))
+                .map(str::to_string)
+                .collect(),
+            unreferenced: defined
+                .into_iter()
+                .filter(|id| !self.contains(id))
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+impl<S> FromIterator<S> for Catalog
+where
+    S: Into<String>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Catalog {
+            ids: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<S> Extend<S> for Catalog
+where
+    S: Into<String>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = S>,
+    {
+        self.ids.extend(iter.into_iter().map(Into::into));
+    }
+}
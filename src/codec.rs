@@ -0,0 +1,193 @@
+//! Compact binary encoding of [`FluentArgs`], for caching arguments between services.
+//!
+//! An encoded argument set starts with the 4-byte magic `FSAR` and a version byte,
+//! followed by the number of arguments and the arguments themselves. Lengths and
+//! counts are encoded as unsigned LEB128 integers. Each argument is its name, a tag
+//! byte, and a payload:
+//!
+//! | Tag | Value                  | Payload                        |
+//! |-----|------------------------|--------------------------------|
+//! | `0` | [`FluentValue::None`]  | None                           |
+//! | `1` | [`FluentValue::Error`] | None                           |
+//! | `2` | String                 | Length and UTF-8 bytes         |
+//! | `3` | Number                 | Little-endian IEEE 754 [`f64`] |
+//!
+//! Number formatting options and custom values are not supported.
+
+use std::convert::TryInto;
+
+use fluent::types::{FluentNumber, FluentNumberOptions};
+use fluent::{FluentArgs, FluentValue};
+use thiserror::Error;
+
+const MAGIC: &[u8; 4] = b"FSAR";
+const VERSION: u8 = 1;
+
+const TAG_NONE: u8 = 0;
+const TAG_ERROR: u8 = 1;
+const TAG_STRING: u8 = 2;
+const TAG_NUMBER: u8 = 3;
+
+/// Binary codec error.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("custom value of `{0}` cannot be encoded")]
+    UnsupportedValue(String),
+    #[error("input does not start with the expected header")]
+    InvalidHeader,
+    #[error("unsupported encoding version {0}")]
+    UnsupportedVersion(u8),
+    #[error("unexpected end of input")]
+    UnexpectedEof,
+    #[error("invalid value tag {0}")]
+    InvalidTag(u8),
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("input bytes do not form a valid UTF-8 encoded string")]
+    NonUtf8Bytes,
+    #[error("unexpected bytes after the encoded arguments")]
+    TrailingBytes,
+}
+
+/// Encodes `args` into the binary format.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentArgs;
+/// use fluent_serde::codec;
+///
+/// let mut args = FluentArgs::new();
+/// args.set("name", "Alice");
+/// args.set("count", 3);
+///
+/// let bytes = codec::encode(&args).unwrap();
+/// let decoded = codec::decode(&bytes).unwrap();
+///
+/// assert_eq!(args.get("name"), decoded.get("name"));
+/// assert_eq!(args.get("count"), decoded.get("count"));
+/// ```
+pub fn encode(args: &FluentArgs) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::with_capacity(16);
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    write_len(&mut out, args.iter().count());
+
+    for (key, value) in args.iter() {
+        write_str(&mut out, key);
+        match value {
+            FluentValue::None => out.push(TAG_NONE),
+            FluentValue::Error => out.push(TAG_ERROR),
+            FluentValue::String(s) => {
+                out.push(TAG_STRING);
+                write_str(&mut out, s);
+            }
+            FluentValue::Number(n) => {
+                out.push(TAG_NUMBER);
+                out.extend_from_slice(&n.value.to_le_bytes());
+            }
+            FluentValue::Custom(_) => return Err(Error::UnsupportedValue(key.to_string())),
+        }
+    }
+
+    Ok(out)
+}
+
+/// Decodes arguments encoded with [`encode`].
+pub fn decode(bytes: &[u8]) -> Result<FluentArgs<'static>, Error> {
+    let mut reader = Reader { bytes };
+
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(Error::InvalidHeader);
+    }
+    match reader.byte()? {
+        VERSION => {}
+        version => return Err(Error::UnsupportedVersion(version)),
+    }
+
+    let count = reader.len()?;
+    let mut args = FluentArgs::with_capacity(count.min(bytes.len()));
+    for _ in 0..count {
+        let key = reader.string()?;
+        let value = match reader.byte()? {
+            TAG_NONE => FluentValue::None,
+            TAG_ERROR => FluentValue::Error,
+            TAG_STRING => FluentValue::String(reader.string()?.into()),
+            TAG_NUMBER => {
+                let bytes = reader.take(8)?.try_into().unwrap();
+                let value = f64::from_le_bytes(bytes);
+                FluentValue::Number(FluentNumber::new(value, FluentNumberOptions::default()))
+            }
+            tag => return Err(Error::InvalidTag(tag)),
+        };
+        args.set(key, value);
+    }
+
+    if reader.bytes.is_empty() {
+        Ok(args)
+    } else {
+        Err(Error::TrailingBytes)
+    }
+}
+
+fn write_len(out: &mut Vec<u8>, mut len: usize) {
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    write_len(out, s.len());
+    out.extend_from_slice(s.as_bytes());
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() < n {
+            return Err(Error::UnexpectedEof);
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn len(&mut self) -> Result<usize, Error> {
+        let mut len = 0usize;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            let bits = (byte & 0x7f) as usize;
+            if shift >= usize::BITS || (bits << shift) >> shift != bits {
+                return Err(Error::InvalidLength);
+            }
+            len |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(len);
+            }
+            shift += 7;
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let len = self.len()?;
+        let bytes = self.take(len)?;
+        std::str::from_utf8(bytes)
+            .map(str::to_string)
+            .map_err(|_| Error::NonUtf8Bytes)
+    }
+}
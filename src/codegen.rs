@@ -0,0 +1,309 @@
+//! Generation of typed argument structs from Fluent resources, for use in build
+//! scripts. Requires the `codegen` feature.
+//!
+//! A struct is generated for each message with a value, with one public field per
+//! variable the message references, including variables of messages it references in
+//! turn. Struct names are the message identifiers in `PascalCase`, and field names in
+//! `snake_case`, renamed back to the original variable names when serialized. A `_`
+//! is appended to names that are Rust keywords, such as `Self_` for the message `self`,
+//! and to struct names that would shadow types and traits of the standard prelude,
+//! such as `Result_` for the message `result`. Variables of a message whose field
+//! names are the same are rejected.
+//!
+//! Variables inferred to be of [`Kind::Number`](crate::placeables::Kind::Number) are
+//! typed as [`f64`]. All other variables are typed as [`String`].
+//!
+//! A `MESSAGE_IDS` constant lists the identifiers of all generated structs, and can
+//! be used to build a [`Catalog`](crate::catalog::Catalog). Each struct implements
+//! [`FluentMessage`](crate::typed::FluentMessage) with its message identifier.
+//! The generated code derives `serde::Serialize`, so the crate including it must
+//! depend on `serde` with the `derive` feature.
+//!
+//! # Example
+//!
+//! In `build.rs`:
+//!
+//! ```rust,no_run
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("messages.rs");
+//! fluent_serde::codegen::generate_files(&["locales/en/app.ftl"], out).unwrap();
+//! println!("cargo:rerun-if-changed=locales/en/app.ftl");
+//! ```
+//!
+//! In the crate:
+//!
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/messages.rs"));
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use fluent::{FluentMessage, FluentResource};
+use fluent_syntax::ast;
+use fluent_syntax::parser::ParserError;
+use thiserror::Error;
+
+use crate::placeables::{Kind, Placeables};
+
+/// Code generation error.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("failed to parse Fluent resource: {}", .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(", "))]
+    Parse(Vec<ParserError>),
+    #[error("message `{0}` is defined more than once")]
+    DuplicateMessage(String),
+    #[error("messages `{0}` and `{1}` map to the same struct name")]
+    DuplicateName(String, String),
+    #[error("variables `{1}` and `{2}` of message `{0}` map to the same field name")]
+    DuplicateField(String, String, String),
+}
+
+/// Generates Rust source code for the messages in the Fluent resource `source`.
+///
+/// # Example
+///
+/// ```rust
+/// let code = fluent_serde::codegen::generate("cart = { $user-name } has { $count } items.").unwrap();
+/// assert!(code.contains("pub struct Cart {"));
+/// assert!(code.contains("pub user_name: ::std::string::String,"));
+///
+/// let code = fluent_serde::codegen::generate("self = Me").unwrap();
+/// assert!(code.contains("pub struct Self_ {"));
+///
+/// let code = fluent_serde::codegen::generate("result = Done").unwrap();
+/// assert!(code.contains("pub struct Result_ {"));
+///
+/// let err = fluent_serde::codegen::generate("hello = { $user-name } { $userName }").unwrap_err();
+/// assert!(matches!(err, fluent_serde::codegen::Error::DuplicateField(..)));
+/// ```
+pub fn generate(source: &str) -> Result<String, Error> {
+    generate_sources(std::iter::once(source.to_string()))
+}
+
+/// Reads the Fluent resources at `inputs`, and writes Rust source code for all of
+/// their messages to `output`.
+pub fn generate_files<I, P, Q>(inputs: I, output: Q) -> Result<(), Error>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let sources = inputs
+        .into_iter()
+        .map(fs::read_to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+    let code = generate_sources(sources)?;
+    fs::write(output, code)?;
+    Ok(())
+}
+
+fn generate_sources<I>(sources: I) -> Result<String, Error>
+where
+    I: IntoIterator<Item = String>,
+{
+    let resources = sources
+        .into_iter()
+        .map(|source| FluentResource::try_new(source).map_err(|(_, errors)| Error::Parse(errors)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut messages = BTreeMap::new();
+    for entry in resources.iter().flat_map(|resource| resource.entries()) {
+        if let ast::Entry::Message(message) = entry {
+            let id = message.id.name;
+            if messages.insert(id, message).is_some() {
+                return Err(Error::DuplicateMessage(id.to_string()));
+            }
+        }
+    }
+
+    let mut names = HashMap::new();
+    let mut out = String::from("// Generated by fluent-serde. Do not edit.\n");
+    let ids = messages
+        .iter()
+        .filter(|(_, message)| message.value.is_some())
+        .map(|(id, _)| *id)
+        .collect::<Vec<_>>();
+    write_ids(&mut out, &ids);
+
+    for id in ids {
+        let name = pascal_case(id);
+        if let Some(other) = names.insert(name.clone(), id) {
+            return Err(Error::DuplicateName(other.to_string(), id.to_string()));
+        }
+
+        let variables = variables(id, &messages);
+        let mut fields = HashMap::new();
+        for variable in variables.keys() {
+            if let Some(other) = fields.insert(field_name(variable), *variable) {
+                return Err(Error::DuplicateField(
+                    id.to_string(),
+                    other.to_string(),
+                    variable.to_string(),
+                ));
+            }
+        }
+        write_struct(&mut out, id, &name, &variables);
+    }
+
+    Ok(out)
+}
+
+fn write_ids(out: &mut String, ids: &[&str]) {
+    writeln!(out).unwrap();
+    writeln!(out, "/// Identifiers of all generated messages.").unwrap();
+    writeln!(out, "pub const MESSAGE_IDS: &[&str] = &[").unwrap();
+    for id in ids {
+        writeln!(out, "    \"{}\",", id).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+fn write_struct(out: &mut String, id: &str, name: &str, variables: &BTreeMap<&str, Kind>) {
+    writeln!(out).unwrap();
+    writeln!(out, "/// Arguments of the `{}` message.", id).unwrap();
+    writeln!(out, "#[derive(Clone, Debug, ::serde::Serialize)]").unwrap();
+    writeln!(out, "pub struct {} {{", name).unwrap();
+    for (variable, kind) in variables {
+        let field = field_name(variable);
+        if field != *variable {
+            writeln!(out, "    #[serde(rename = \"{}\")]", variable).unwrap();
+        }
+        let ty = match kind {
+            Kind::String => "::std::string::String",
+            Kind::Number => "f64",
+        };
+        writeln!(out, "    pub {}: {},", field, ty).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "impl ::fluent_serde::typed::FluentMessage for {} {{",
+        name
+    )
+    .unwrap();
+    writeln!(out, "    const ID: &'static str = \"{}\";", id).unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+/// Collects the variables referenced by message `id`, following references to
+/// other messages.
+fn variables<'a>(
+    id: &'a str,
+    messages: &BTreeMap<&'a str, &'a ast::Message<&'a str>>,
+) -> BTreeMap<&'a str, Kind> {
+    let message = FluentMessage::from(messages[id]);
+    Placeables::from_message_with_references(&message, |id| {
+        messages
+            .get(id)
+            .map(|message| FluentMessage::from(*message))
+    })
+    .variables
+}
+
+/// Names that generated structs must not take: `Self`, the only keyword in
+/// `PascalCase`, and the types and traits of the standard prelude, which a struct
+/// would shadow in the module including the generated code.
+const RESERVED_TYPES: &[&str] = &[
+    "AsMut",
+    "AsRef",
+    "Box",
+    "Clone",
+    "Copy",
+    "Default",
+    "DoubleEndedIterator",
+    "Drop",
+    "Eq",
+    "ExactSizeIterator",
+    "Extend",
+    "Fn",
+    "FnMut",
+    "FnOnce",
+    "From",
+    "FromIterator",
+    "Into",
+    "IntoIterator",
+    "Iterator",
+    "Option",
+    "Ord",
+    "PartialEq",
+    "PartialOrd",
+    "Result",
+    "Self",
+    "Send",
+    "Sized",
+    "String",
+    "Sync",
+    "ToOwned",
+    "ToString",
+    "TryFrom",
+    "TryInto",
+    "Unpin",
+    "Vec",
+];
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Splits a Fluent identifier into lowercase words, on `-`, `_`, and lowercase to
+/// uppercase transitions.
+fn words(id: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for c in id.chars() {
+        if c == '-' || c == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && prev_lower {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        current.push(c.to_ascii_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn pascal_case(id: &str) -> String {
+    let mut name = words(id)
+        .into_iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<String>();
+    if RESERVED_TYPES.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
+
+fn field_name(variable: &str) -> String {
+    let mut name = words(variable).join("_");
+    if KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
@@ -0,0 +1,47 @@
+//! Helpers for building [`FluentArgs`] from CSV records, such as spreadsheet
+//! exports for mail merge. Requires the `csv` feature.
+
+use std::io;
+
+use fluent::FluentArgs;
+
+/// Reads CSV records from `reader` as [`FluentArgs`], one per record, using the
+/// header row as argument names. All values are strings, so that values such as
+/// postal codes keep their leading zeros.
+///
+/// Records are read as the iterator advances. An error reading the header row is
+/// returned immediately.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+///
+/// let data = "name,zip\nAlice,01234\nBob,98765\n";
+/// let args = fluent_serde::csv::args_from_reader(data.as_bytes())
+///     .unwrap()
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(2, args.len());
+/// assert_eq!(Some(&FluentValue::from("Alice")), args[0].get("name"));
+/// assert_eq!(Some(&FluentValue::from("01234")), args[0].get("zip"));
+/// ```
+pub fn args_from_reader<R>(
+    reader: R,
+) -> Result<impl Iterator<Item = Result<FluentArgs<'static>, ::csv::Error>>, ::csv::Error>
+where
+    R: io::Read,
+{
+    let mut reader = ::csv::Reader::from_reader(reader);
+    let headers = reader.headers()?.clone();
+
+    Ok(reader.into_records().map(move |record| {
+        let record = record?;
+        let mut args = FluentArgs::with_capacity(headers.len());
+        for (key, value) in headers.iter().zip(record.iter()) {
+            args.set(key.to_string(), value.to_string());
+        }
+        Ok(args)
+    }))
+}
@@ -0,0 +1,180 @@
+//! Deserialization.
+
+use std::fmt;
+
+use fluent::types::{FluentNumber, FluentNumberOptions};
+use fluent::{FluentArgs, FluentValue};
+use serde::de::{self, DeserializeSeed, MapAccess, Visitor};
+use serde::Deserializer;
+
+/// Reads a [`FluentArgs`] directly from any [`Deserializer`], without going through
+/// an intermediate typed structure.
+///
+/// The input must be a map from strings to scalar values. Values are converted the
+/// same way [`ValueSerializer`](crate::ser::ValueSerializer) converts them: strings
+/// and valid UTF-8 bytes become strings, numbers and booleans become numbers, and
+/// unit or absent values become [`FluentValue::None`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::de::args_from_deserializer;
+/// use serde::de::value::{Error, MapDeserializer};
+///
+/// let entries = vec![("name", "Alice"), ("role", "admin")];
+/// let deserializer = MapDeserializer::<_, Error>::new(entries.into_iter());
+/// let args = args_from_deserializer(deserializer).unwrap();
+///
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// assert_eq!(Some(&FluentValue::from("admin")), args.get("role"));
+/// ```
+pub fn args_from_deserializer<'de, D>(deserializer: D) -> Result<FluentArgs<'static>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(ArgsVisitor)
+}
+
+pub(crate) struct ArgsVisitor;
+
+impl<'de> Visitor<'de> for ArgsVisitor {
+    type Value = FluentArgs<'static>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of Fluent arguments")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut args = FluentArgs::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(key) = map.next_key::<String>()? {
+            let value = map.next_value_seed(ValueSeed)?;
+            args.set(key, value);
+        }
+        Ok(args)
+    }
+}
+
+pub(crate) struct ValueSeed;
+
+impl<'de> DeserializeSeed<'de> for ValueSeed {
+    type Value = FluentValue<'static>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+fn number(v: f64) -> FluentValue<'static> {
+    FluentValue::Number(FluentNumber::new(v, FluentNumberOptions::default()))
+}
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = FluentValue<'static>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string, number, boolean, or nothing")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(number(if v { 1.0 } else { 0.0 }))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(number(v as f64))
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(number(v as f64))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(number(v as f64))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(number(v as f64))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(number(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(FluentValue::String(v.to_string().into()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(FluentValue::String(v.into()))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match std::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(FluentValue::None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(FluentValue::None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
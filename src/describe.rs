@@ -0,0 +1,130 @@
+//! Introspection of the arguments a value serializes into.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use fluent::FluentValue;
+use serde::Serialize;
+
+use crate::metadata::ArgKind;
+use crate::ser::{ArgSink, ArgsSerializer, Error, SerializerOptions};
+
+/// Names and kinds of the arguments a value serializes into.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArgsSchema {
+    args: BTreeMap<Cow<'static, str>, ArgKind>,
+}
+
+impl ArgsSchema {
+    /// Returns the kind of argument `key`, if it is produced.
+    pub fn get(&self, key: &str) -> Option<ArgKind> {
+        self.args.get(key).copied()
+    }
+
+    /// Returns `true` if argument `key` is produced.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.args.contains_key(key)
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if no arguments are produced.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns an iterator over the names of the arguments, in order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.args.keys().map(|key| &**key)
+    }
+
+    /// Returns an iterator over the arguments and their kinds, in order of name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, ArgKind)> {
+        self.args.iter().map(|(key, kind)| (&**key, *kind))
+    }
+}
+
+/// Describes the arguments `value` would serialize into with
+/// [`ArgsSerializer`](crate::ArgsSerializer), without building a
+/// [`FluentArgs`](fluent::FluentArgs).
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_serde::metadata::ArgKind;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Cart {
+///     user: String,
+///     count: u32,
+///     coupon: Option<String>,
+/// }
+///
+/// let cart = Cart { user: "Alice".into(), count: 3, coupon: None };
+/// let schema = fluent_serde::describe(&cart).unwrap();
+///
+/// assert_eq!(Some(ArgKind::String), schema.get("user"));
+/// assert_eq!(Some(ArgKind::Number), schema.get("count"));
+/// assert_eq!(Some(ArgKind::None), schema.get("coupon"));
+/// ```
+pub fn describe<T>(value: &T) -> Result<ArgsSchema, Error>
+where
+    T: ?Sized + Serialize,
+{
+    describe_with_options(value, SerializerOptions::default())
+}
+
+/// Describes the arguments `value` would serialize into with an
+/// [`ArgsSerializer`](crate::ArgsSerializer) with `options`.
+///
+/// Values are serialized as they would be with `options`, so errors, skipped
+/// results, and errors recorded as values are described as they would be produced,
+/// but strings are neither copied nor transformed.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_serde::describe::describe_with_options;
+/// use fluent_serde::metadata::ArgKind;
+/// use fluent_serde::ser::{ResultPolicy, SerializerOptions};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Upload {
+///     status: Result<u32, String>,
+///     done: bool,
+/// }
+///
+/// let upload = Upload { status: Err("timed out".into()), done: false };
+/// let options = SerializerOptions::new().results(ResultPolicy::ErrorKey);
+/// let schema = describe_with_options(&upload, options).unwrap();
+/// assert_eq!(Some(ArgKind::None), schema.get("status"));
+/// assert_eq!(Some(ArgKind::String), schema.get("status-error"));
+///
+/// let options = SerializerOptions::new().strict(true);
+/// assert!(describe_with_options(&upload, options).is_err());
+/// ```
+pub fn describe_with_options<T>(value: &T, options: SerializerOptions) -> Result<ArgsSchema, Error>
+where
+    T: ?Sized + Serialize,
+{
+    let mut ser = ArgsSerializer::with_sink(SchemaSink::default());
+    ser.set_options(options);
+    ser.set_kind_only(true);
+    value.serialize(&mut ser)?;
+    Ok(ser.into_sink().0)
+}
+
+/// Records the kinds of the arguments set, without keeping their values.
+#[derive(Default)]
+struct SchemaSink(ArgsSchema);
+
+impl ArgSink for SchemaSink {
+    fn set(&mut self, key: Cow<'static, str>, value: FluentValue<'static>) {
+        self.0.args.insert(key, ArgKind::of(&value));
+    }
+}
@@ -0,0 +1,92 @@
+//! Comparison of [`FluentArgs`].
+
+use fluent::{FluentArgs, FluentValue};
+
+/// Differences between two [`FluentArgs`], returned by [`diff`].
+#[derive(Debug, Default)]
+pub struct ArgsDiff {
+    added: Vec<(String, FluentValue<'static>)>,
+    removed: Vec<(String, FluentValue<'static>)>,
+    changed: Vec<(String, FluentValue<'static>, FluentValue<'static>)>,
+}
+
+impl ArgsDiff {
+    /// Returns the arguments only in the second [`FluentArgs`], with their values.
+    pub fn added(&self) -> &[(String, FluentValue<'static>)] {
+        &self.added
+    }
+
+    /// Returns the arguments only in the first [`FluentArgs`], with their values.
+    pub fn removed(&self) -> &[(String, FluentValue<'static>)] {
+        &self.removed
+    }
+
+    /// Returns the arguments in both [`FluentArgs`] with different values, with the
+    /// first value and then the second.
+    pub fn changed(&self) -> &[(String, FluentValue<'static>, FluentValue<'static>)] {
+        &self.changed
+    }
+
+    /// Returns `true` if both [`FluentArgs`] hold the same arguments.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Lists the arguments added, removed, and changed from `a` to `b`.
+///
+/// Values are compared by kind and value, so [`FluentValue::None`] and
+/// [`FluentValue::Error`] are equal to themselves, unlike with `==`. Numbers are
+/// equal only if their formatting options are too.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentArgs, FluentValue};
+///
+/// let mut a = FluentArgs::new();
+/// a.set("name", "Alice");
+/// a.set("count", 3);
+///
+/// let mut b = FluentArgs::new();
+/// b.set("name", "Alice");
+/// b.set("count", 4);
+/// b.set("title", "Hello");
+///
+/// let diff = fluent_serde::diff(&a, &b);
+/// assert!(diff.removed().is_empty());
+/// assert_eq!("title", diff.added()[0].0);
+/// assert_eq!(
+///     ("count".to_string(), FluentValue::from(3), FluentValue::from(4)),
+///     diff.changed()[0],
+/// );
+/// ```
+pub fn diff(a: &FluentArgs<'_>, b: &FluentArgs<'_>) -> ArgsDiff {
+    let mut diff = ArgsDiff::default();
+
+    for (key, old) in a.iter() {
+        match b.get(key) {
+            Some(new) if values_eq(old, new) => {}
+            Some(new) => {
+                diff.changed
+                    .push((key.to_string(), old.into_owned(), new.into_owned()));
+            }
+            None => diff.removed.push((key.to_string(), old.into_owned())),
+        }
+    }
+
+    for (key, new) in b.iter() {
+        if a.get(key).is_none() {
+            diff.added.push((key.to_string(), new.into_owned()));
+        }
+    }
+
+    diff
+}
+
+fn values_eq(a: &FluentValue<'_>, b: &FluentValue<'_>) -> bool {
+    match (a, b) {
+        (FluentValue::None, FluentValue::None) | (FluentValue::Error, FluentValue::Error) => true,
+        (a, b) => a == b,
+    }
+}
@@ -0,0 +1,40 @@
+//! Building [`FluentArgs`] from environment variables, for command-line tools
+//! whose branding arguments come from the environment.
+
+use std::env;
+
+use fluent::FluentArgs;
+
+/// Collects the environment variables whose names start with `prefix` into a
+/// [`FluentArgs`]. Argument names are the rest of the variable names, lowercased,
+/// with underscores replaced by hyphens. Values are strings.
+///
+/// Variables whose names or values are not valid Unicode are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+///
+/// std::env::set_var("APP_MSG_PRODUCT_NAME", "Frobnicator");
+/// let args = fluent_serde::env::args_from_env("APP_MSG_");
+/// assert_eq!(Some(&FluentValue::from("Frobnicator")), args.get("product-name"));
+/// ```
+pub fn args_from_env(prefix: &str) -> FluentArgs<'static> {
+    env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+        .filter_map(|(key, value)| {
+            let name = key.strip_prefix(prefix).filter(|name| !name.is_empty())?;
+            Some((arg_name(name), value))
+        })
+        .collect()
+}
+
+fn arg_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '_' => '-',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
@@ -0,0 +1,48 @@
+//! Serialization of type-erased values with `erased-serde`. Requires the `erased`
+//! feature.
+
+use fluent::{FluentArgs, FluentValue};
+
+use crate::ser::{self, ArgsSerializer, SerializerOptions, ValueSerializer};
+
+/// Serializes a type-erased `value` into a [`FluentArgs`], with the default options.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::erased::to_args_erased;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     name: String,
+/// }
+///
+/// let value: Box<dyn erased_serde::Serialize> = Box::new(Args { name: "Alice".into() });
+/// let args = to_args_erased(&*value).unwrap();
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// ```
+pub fn to_args_erased(
+    value: &dyn erased_serde::Serialize,
+) -> Result<FluentArgs<'static>, ser::Error> {
+    to_args_erased_with_options(value, SerializerOptions::default())
+}
+
+/// Serializes a type-erased `value` into a [`FluentArgs`], with `options`.
+pub fn to_args_erased_with_options(
+    value: &dyn erased_serde::Serialize,
+    options: SerializerOptions,
+) -> Result<FluentArgs<'static>, ser::Error> {
+    let mut ser = ArgsSerializer::with_options(options);
+    ser.merge_from(value)?;
+    Ok(ser.done())
+}
+
+/// Serializes a type-erased `value` into a [`FluentValue`], with the default
+/// options.
+pub fn to_value_erased(
+    value: &dyn erased_serde::Serialize,
+) -> Result<FluentValue<'static>, ser::Error> {
+    serde::Serialize::serialize(value, ValueSerializer::new())
+}
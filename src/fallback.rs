@@ -0,0 +1,116 @@
+//! Integration with `fluent-fallback`. Requires the `fluent-fallback` feature.
+
+use fluent_fallback::env::LocalesProvider;
+use fluent_fallback::generator::BundleGenerator;
+use fluent_fallback::{Localization, LocalizationError};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::ser;
+
+/// Formatting error.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum FallbackError {
+    #[error("failed to serialize arguments: {0}")]
+    Args(#[from] ser::Error),
+    #[error(transparent)]
+    Localization(#[from] LocalizationError),
+}
+
+/// Extension trait for formatting [`Localization`] values with serialized arguments.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_fallback::generator::{BundleGenerator, FluentBundleResult};
+/// use fluent_fallback::types::ResourceId;
+/// use fluent_fallback::Localization;
+/// use fluent_serde::fallback::LocalizationExt;
+/// use rustc_hash::FxHashSet;
+/// use serde::Serialize;
+/// use unic_langid::LanguageIdentifier;
+///
+/// struct Generator;
+///
+/// impl BundleGenerator for Generator {
+///     type Resource = FluentResource;
+///     type LocalesIter = std::vec::IntoIter<LanguageIdentifier>;
+///     type Iter = std::vec::IntoIter<FluentBundleResult<FluentResource>>;
+///     type Stream = futures::stream::Iter<Self::Iter>;
+///
+///     fn bundles_iter(
+///         &self,
+///         locales: Self::LocalesIter,
+///         _res_ids: FxHashSet<ResourceId>,
+///     ) -> Self::Iter {
+///         let bundles = locales.map(|locale| {
+///             let ftl = "cart = { $user } has { $count } items.";
+///             let resource = FluentResource::try_new(ftl.into()).unwrap();
+///             let mut bundle = FluentBundle::new(vec![locale]);
+///             bundle.set_use_isolating(false);
+///             match bundle.add_resource(resource) {
+///                 Ok(()) => Ok(bundle),
+///                 Err(errors) => Err((bundle, errors)),
+///             }
+///         });
+///         bundles.collect::<Vec<_>>().into_iter()
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Cart {
+///     user: String,
+///     count: u32,
+/// }
+///
+/// let locales: Vec<LanguageIdentifier> = vec!["en".parse().unwrap()];
+/// let localization = Localization::with_env(vec![ResourceId::from("app.ftl")], true, locales, Generator);
+///
+/// let cart = Cart { user: "Alice".into(), count: 3 };
+/// let mut errors = Vec::new();
+/// let text = localization.format_value_with("cart", &cart, &mut errors).unwrap();
+/// assert_eq!(Some("Alice has 3 items.".to_string()), text);
+/// assert!(errors.is_empty());
+/// ```
+pub trait LocalizationExt {
+    /// Synchronously formats the value of message `id` across the fallback chain,
+    /// with arguments serialized from `args`.
+    ///
+    /// Returns `Ok(None)` if no bundle in the chain contains the message. Errors
+    /// encountered while formatting are appended to `errors`, as with
+    /// `format_value_sync`.
+    fn format_value_with<T>(
+        &self,
+        id: &str,
+        args: &T,
+        errors: &mut Vec<LocalizationError>,
+    ) -> Result<Option<String>, FallbackError>
+    where
+        T: ?Sized + Serialize;
+}
+
+impl<G, P> LocalizationExt for Localization<G, P>
+where
+    G: BundleGenerator<LocalesIter = P::Iter>,
+    P: LocalesProvider,
+{
+    fn format_value_with<T>(
+        &self,
+        id: &str,
+        args: &T,
+        errors: &mut Vec<LocalizationError>,
+    ) -> Result<Option<String>, FallbackError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let args = ser::to_args(args)?;
+
+        let value = self
+            .bundles()
+            .format_value_sync(id, Some(&args), errors)?
+            .map(|value| value.into_owned());
+        Ok(value)
+    }
+}
@@ -0,0 +1,192 @@
+//! Helpers for building [`FluentArgs`] from JSON. Requires the `json` feature.
+//!
+//! Only JSON objects can be converted. Their values must be scalars: strings and
+//! numbers are converted as by [`ValueSerializer`], booleans become `1.0` or `0.0`,
+//! and `null` becomes [`FluentValue::None`]. Arrays and nested objects are
+//! rejected.
+//!
+//! Single values are converted with [`value_from_json`] and [`value_to_json`].
+
+use std::io;
+
+use fluent::{FluentArgs, FluentValue};
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::ser::{self, ValueSerializer};
+
+/// JSON conversion error.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("input is not valid JSON: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("expected a JSON object, found {0}")]
+    NotAnObject(&'static str),
+    #[error("value of `{key}` is an unsupported JSON {kind}")]
+    UnsupportedValue { key: String, kind: &'static str },
+    #[error("expected a scalar JSON value, found {0}")]
+    NotAScalar(&'static str),
+    #[error("custom Fluent values cannot be converted to JSON")]
+    CustomValue,
+    #[error("{0} cannot be represented as a JSON number")]
+    NonFiniteNumber(f64),
+    #[error(transparent)]
+    Ser(#[from] ser::Error),
+}
+
+/// Builds a [`FluentArgs`] from a JSON object of scalars.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use serde_json::json;
+///
+/// let args = fluent_serde::json::args_from_value(&json!({
+///     "name": "Alice",
+///     "count": 3,
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+/// ```
+pub fn args_from_value(value: &Value) -> Result<FluentArgs<'static>, Error> {
+    let object = match value {
+        Value::Object(object) => object,
+        other => return Err(Error::NotAnObject(kind(other))),
+    };
+
+    let mut args = FluentArgs::with_capacity(object.len());
+    for (key, value) in object {
+        if let Value::Array(_) | Value::Object(_) = value {
+            return Err(Error::UnsupportedValue {
+                key: key.clone(),
+                kind: kind(value),
+            });
+        }
+
+        args.set(key.clone(), value.serialize(ValueSerializer::new())?);
+    }
+
+    Ok(args)
+}
+
+/// Parses a JSON object of scalars into a [`FluentArgs`].
+///
+/// See [`args_from_value`] for the accepted shapes.
+pub fn args_from_str(s: &str) -> Result<FluentArgs<'static>, Error> {
+    args_from_value(&serde_json::from_str(s)?)
+}
+
+/// Reads newline-delimited JSON objects of scalars from `reader` as
+/// [`FluentArgs`], one at a time, without reading the whole input first.
+///
+/// See [`args_from_value`] for the accepted shapes. Reading stops after an error
+/// in the JSON syntax, but not after an object that cannot be converted.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::json::args_stream_from_ndjson;
+///
+/// let input = "{\"name\": \"Alice\"}\n{\"name\": \"Bob\"}\n";
+/// let args = args_stream_from_ndjson(input.as_bytes())
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(Some(&FluentValue::from("Bob")), args[1].get("name"));
+/// ```
+pub fn args_stream_from_ndjson<R>(
+    reader: R,
+) -> impl Iterator<Item = Result<FluentArgs<'static>, Error>>
+where
+    R: io::Read,
+{
+    let mut values = serde_json::Deserializer::from_reader(reader).into_iter::<Value>();
+    let mut failed = false;
+    std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        Some(match values.next()? {
+            Ok(value) => args_from_value(&value),
+            Err(err) => {
+                failed = true;
+                Err(err.into())
+            }
+        })
+    })
+}
+
+/// Converts a scalar JSON value into a [`FluentValue`], as the values of
+/// [`args_from_value`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::json::value_from_json;
+/// use serde_json::json;
+///
+/// assert_eq!(FluentValue::from(3), value_from_json(&json!(3)).unwrap());
+/// assert!(value_from_json(&json!([1, 2])).is_err());
+/// ```
+pub fn value_from_json(value: &Value) -> Result<FluentValue<'static>, Error> {
+    match value {
+        Value::Array(_) | Value::Object(_) => Err(Error::NotAScalar(kind(value))),
+        value => Ok(value.serialize(ValueSerializer::new())?),
+    }
+}
+
+/// Converts a [`FluentValue`] into a JSON value.
+///
+/// Strings become JSON strings, and numbers JSON numbers, which are integers if
+/// the value is integral. Number formatting options are not converted.
+/// [`FluentValue::None`] and [`FluentValue::Error`] become `null`.
+/// [`FluentValue::Custom`] values and non-finite numbers have no JSON
+/// representation, and are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::json::value_to_json;
+/// use serde_json::json;
+///
+/// assert_eq!(json!("Alice"), value_to_json(&FluentValue::from("Alice")).unwrap());
+/// assert_eq!(json!(3), value_to_json(&FluentValue::from(3)).unwrap());
+/// assert_eq!(json!(null), value_to_json(&FluentValue::None).unwrap());
+/// ```
+pub fn value_to_json(value: &FluentValue<'_>) -> Result<Value, Error> {
+    match value {
+        FluentValue::String(s) => Ok(Value::String(s.to_string())),
+        FluentValue::Number(n) => {
+            let n = n.value;
+            // The bounds are exact powers of two, so the cast below cannot saturate.
+            if n.fract() == 0.0 && n >= -(2f64.powi(63)) && n < 2f64.powi(63) {
+                Ok(Value::from(n as i64))
+            } else {
+                serde_json::Number::from_f64(n)
+                    .map(Value::Number)
+                    .ok_or(Error::NonFiniteNumber(n))
+            }
+        }
+        FluentValue::Custom(_) => Err(Error::CustomValue),
+        FluentValue::None | FluentValue::Error => Ok(Value::Null),
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
@@ -1,7 +1,49 @@
- 
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+pub mod canonical;
+pub mod catalog;
+pub mod codec;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod de;
+pub mod describe;
+pub mod diff;
+pub mod env;
+#[cfg(feature = "erased")]
+pub mod erased;
+#[cfg(feature = "fluent-fallback")]
+pub mod fallback;
+#[cfg(feature = "json")]
+pub mod json;
+#[doc(hidden)]
+pub mod macros;
+pub mod metadata;
+pub mod placeables;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+pub mod render;
+pub mod resolver;
 pub mod ser;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "toml")]
+pub mod toml;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+pub mod typed;
+pub mod validate;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "web")]
+pub mod web;
+pub mod wrappers;
+#[cfg(feature = "yaml")]
+pub mod yaml;
 
-pub use ser::{ArgsSerializer, ValueSerializer};
+pub use de::args_from_deserializer;
+pub use describe::{describe, ArgsSchema};
+pub use diff::{diff, ArgsDiff};
+pub use ser::{to_args, to_value, ArgsSerializer, IntoFluentValue, ToFluentArgs, ValueSerializer};
@@ -0,0 +1,155 @@
+//! Argument construction and formatting macros.
+
+/// Formats a message from a [`FluentBundle`](fluent::FluentBundle) with inline
+/// arguments, in one expression.
+///
+/// Each argument value is serialized with [`ValueSerializer`](crate::ValueSerializer),
+/// so any type it supports can be used. Argument names can be identifiers or string
+/// literals, the latter for names that are not valid Rust identifiers. Evaluates to
+/// a `Result<String, ResolveError>`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::fluent_format;
+///
+/// let ftl = "cart = { $user-name } has { $count } items.";
+/// let resource = FluentResource::try_new(ftl.into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let name = "Alice";
+/// let text = fluent_format!(bundle, "cart", { "user-name": name, count: 3 }).unwrap();
+/// assert_eq!("Alice has 3 items.", text);
+/// ```
+#[macro_export]
+macro_rules! fluent_format {
+    ($bundle:expr, $id:expr $(,)?) => {
+        $crate::fluent_format!($bundle, $id, {})
+    };
+    ($bundle:expr, $id:expr, { $($key:tt : $value:expr),* $(,)? } $(,)?) => {
+        $crate::macros::__private::ArgsBuilder::new()
+            $(.arg($crate::__fluent_format_key!($key), &$value))*
+            .format(&$bundle, $id)
+    };
+}
+
+/// Builds a [`FluentArgs`](fluent::FluentArgs) with inline arguments, as an
+/// option-aware replacement for [`fluent_args!`](fluent::fluent_args).
+///
+/// Each argument value is serialized with an [`ArgsSerializer`](crate::ArgsSerializer),
+/// so any type supported by [`ValueSerializer`](crate::ValueSerializer) can be
+/// used. [`SerializerOptions`](crate::ser::SerializerOptions) can be given before
+/// the arguments, followed by a semicolon. Argument names can be identifiers or
+/// string literals. Evaluates to a `Result<FluentArgs<'static>, ser::Error>`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use fluent::FluentValue;
+/// use fluent_serde::args;
+/// use fluent_serde::ser::SerializerOptions;
+///
+/// let name = "<b>Alice</b>";
+/// let args = args! { "user" => name, "count" => 3 }.unwrap();
+/// assert_eq!(Some(&FluentValue::from(name)), args.get("user"));
+/// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+///
+/// let options = SerializerOptions::new().html_escape(true);
+/// let args = args! { options; "user" => name }.unwrap();
+/// assert_eq!(
+///     Some(&FluentValue::String(Cow::Borrowed("&lt;b&gt;Alice&lt;/b&gt;"))),
+///     args.get("user"),
+/// );
+/// ```
+#[macro_export]
+macro_rules! args {
+    ($($key:tt => $value:expr),* $(,)?) => {
+        $crate::macros::__private::ArgsBuilder::new()
+            $(.arg($crate::__fluent_format_key!($key), &$value))*
+            .build()
+    };
+    ($options:expr; $($key:tt => $value:expr),* $(,)?) => {
+        $crate::macros::__private::ArgsBuilder::with_options($options)
+            $(.arg($crate::__fluent_format_key!($key), &$value))*
+            .build()
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fluent_format_key {
+    ($key:ident) => {
+        stringify!($key)
+    };
+    ($key:literal) => {
+        $key
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::borrow::Borrow;
+
+    use fluent::bundle::FluentBundle;
+    use fluent::memoizer::MemoizerKind;
+    use fluent::{FluentArgs, FluentResource};
+    use serde::Serialize;
+
+    use crate::resolver::{format_message, ResolveError};
+    use crate::ser::{self, ArgsSerializer, SerializerOptions};
+
+    pub struct ArgsBuilder {
+        ser: ArgsSerializer,
+        error: Option<ser::Error>,
+    }
+
+    impl ArgsBuilder {
+        #[allow(clippy::new_without_default)]
+        pub fn new() -> Self {
+            Self::with_options(SerializerOptions::default())
+        }
+
+        pub fn with_options(options: SerializerOptions) -> Self {
+            ArgsBuilder {
+                ser: ArgsSerializer::with_options(options),
+                error: None,
+            }
+        }
+
+        pub fn arg<T>(mut self, key: &'static str, value: &T) -> Self
+        where
+            T: ?Sized + Serialize,
+        {
+            if self.error.is_none() {
+                if let Err(err) = self.ser.insert(key, value) {
+                    self.error = Some(err);
+                }
+            }
+            self
+        }
+
+        pub fn build(self) -> Result<FluentArgs<'static>, ser::Error> {
+            match self.error {
+                Some(err) => Err(err),
+                None => Ok(self.ser.done()),
+            }
+        }
+
+        pub fn format<R, M>(
+            self,
+            bundle: &FluentBundle<R, M>,
+            id: &str,
+        ) -> Result<String, ResolveError>
+        where
+            R: Borrow<FluentResource>,
+            M: MemoizerKind,
+        {
+            format_message(bundle, id, move || Ok(self.build()?), &mut Vec::new())
+        }
+    }
+}
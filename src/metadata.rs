@@ -0,0 +1,230 @@
+//! Argument metadata for translation management systems.
+//!
+//! [`Metadata`] describes the arguments of a set of messages, with example values,
+//! so translators know which variables exist and what they hold. It implements
+//! `Serialize`, and can be written as JSON with the `json` feature. [`ftl_comment`]
+//! formats the same information as an FTL comment.
+
+use std::fmt;
+
+use fluent::FluentValue;
+use serde::{Deserialize, Serialize};
+
+use crate::ser;
+use crate::typed::FluentMessage;
+
+/// Kind of an argument value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ArgKind {
+    String,
+    Number,
+    None,
+    Error,
+    Custom,
+}
+
+impl ArgKind {
+    /// Returns the kind of `value`.
+    pub fn of(value: &FluentValue) -> Self {
+        match value {
+            FluentValue::String(_) => ArgKind::String,
+            FluentValue::Number(_) => ArgKind::Number,
+            FluentValue::None => ArgKind::None,
+            FluentValue::Error => ArgKind::Error,
+            FluentValue::Custom(_) => ArgKind::Custom,
+        }
+    }
+}
+
+impl fmt::Display for ArgKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ArgKind::String => "string",
+            ArgKind::Number => "number",
+            ArgKind::None => "none",
+            ArgKind::Error => "error",
+            ArgKind::Custom => "custom",
+        })
+    }
+}
+
+/// Example value of an argument.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Example {
+    String(String),
+    Number(f64),
+}
+
+/// Metadata of a single argument.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArgMetadata {
+    /// Name of the argument.
+    pub name: String,
+    /// Kind of the argument value.
+    #[serde(rename = "type")]
+    pub kind: ArgKind,
+    /// Example value of the argument, if it is a string or a number.
+    pub example: Option<Example>,
+}
+
+/// Metadata of a message and its arguments.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageMetadata {
+    /// Identifier of the message.
+    pub id: String,
+    /// Arguments of the message, sorted by name.
+    pub args: Vec<ArgMetadata>,
+}
+
+impl MessageMetadata {
+    /// Creates metadata for message `id`, with the arguments `example` serializes
+    /// into as examples.
+    pub fn new<T>(id: impl Into<String>, example: &T) -> Result<Self, ser::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let args = ser::to_args(example)?
+            .iter()
+            .map(|(name, value)| ArgMetadata {
+                name: name.to_string(),
+                kind: ArgKind::of(value),
+                example: match value {
+                    FluentValue::String(s) => Some(Example::String(s.to_string())),
+                    FluentValue::Number(n) => Some(Example::Number(n.value)),
+                    _ => None,
+                },
+            })
+            .collect();
+
+        Ok(MessageMetadata {
+            id: id.into(),
+            args,
+        })
+    }
+
+    /// Formats an FTL comment listing the arguments and their kinds, to document
+    /// the message for translators. The comment does not end with a newline.
+    pub fn ftl_comment(&self) -> String {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("${} ({})", arg.name, arg.kind))
+            .collect::<Vec<_>>();
+
+        comment(&args)
+    }
+}
+
+/// Formats an FTL comment listing the arguments `args` serializes into and their
+/// kinds, ready to paste above a new message.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Serialize;
+///
+/// #[derive(Default, Serialize)]
+/// struct Cart {
+///     #[serde(rename = "user-name")]
+///     user_name: String,
+///     count: u32,
+/// }
+///
+/// let comment = fluent_serde::metadata::ftl_comment(&Cart::default()).unwrap();
+/// assert_eq!("# $count (number), $user-name (string)", comment);
+/// ```
+pub fn ftl_comment<T>(args: &T) -> Result<String, ser::Error>
+where
+    T: ?Sized + Serialize,
+{
+    let schema = crate::describe(args)?;
+    let args = schema
+        .iter()
+        .map(|(name, kind)| format!("${} ({})", name, kind))
+        .collect::<Vec<_>>();
+    Ok(comment(&args))
+}
+
+fn comment(args: &[String]) -> String {
+    if args.is_empty() {
+        "# No variables.".to_string()
+    } else {
+        format!("# {}", args.join(", "))
+    }
+}
+
+/// Argument metadata of a set of messages.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_serde::metadata::{ArgKind, Example, Metadata};
+/// use fluent_serde::typed::FluentMessage;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Cart {
+///     user: String,
+///     count: u32,
+/// }
+///
+/// impl FluentMessage for Cart {
+///     const ID: &'static str = "cart";
+/// }
+///
+/// impl Default for Cart {
+///     fn default() -> Self {
+///         Cart { user: "Alice".into(), count: 3 }
+///     }
+/// }
+///
+/// let mut metadata = Metadata::new();
+/// metadata.add_default::<Cart>().unwrap();
+///
+/// assert_eq!("cart", metadata.messages[0].id);
+/// let args = &metadata.messages[0].args;
+/// assert_eq!("count", args[0].name);
+/// assert_eq!(ArgKind::Number, args[0].kind);
+/// assert_eq!(Some(Example::Number(3.0)), args[0].example);
+/// assert_eq!(Some(Example::String("Alice".into())), args[1].example);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Messages, in order of addition.
+    pub messages: Vec<MessageMetadata>,
+}
+
+impl Metadata {
+    /// Creates an empty [`Metadata`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds metadata for message `id`, with the arguments `example` serializes into
+    /// as examples.
+    pub fn add<T>(&mut self, id: impl Into<String>, example: &T) -> Result<(), ser::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.messages.push(MessageMetadata::new(id, example)?);
+        Ok(())
+    }
+
+    /// Adds metadata for message [`M::ID`](FluentMessage::ID), with the [`Default`]
+    /// value of `M` as example.
+    pub fn add_default<M>(&mut self) -> Result<(), ser::Error>
+    where
+        M: FluentMessage + Default,
+    {
+        self.add(M::ID, &M::default())
+    }
+
+    /// Writes the metadata as pretty-printed JSON. Requires the `json` feature.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
@@ -0,0 +1,241 @@
+//! Extraction of variable references, select keys, and other placeables from Fluent
+//! messages and patterns.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fluent::FluentMessage;
+use fluent_syntax::ast;
+
+/// Kind of value a variable is expected to hold, inferred from its uses.
+///
+/// Variables used as arguments of `NUMBER`, or as selectors with numeric or plural
+/// category variant keys, are numbers. All other variables are strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Kind {
+    String,
+    Number,
+}
+
+/// Placeables referenced by a message or a set of patterns.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::placeables::{Kind, Placeables};
+///
+/// let ftl = "
+/// cart = { $count ->
+///         [one] { $user } has one item.
+///        *[other] { $user } has { $count } items.
+///     }
+///     .title = Cart of { $user }
+/// ";
+/// let resource = FluentResource::try_new(ftl.into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let placeables = Placeables::from_message(&bundle.get_message("cart").unwrap());
+/// assert_eq!(Some(&Kind::Number), placeables.variables.get("count"));
+/// assert_eq!(Some(&Kind::String), placeables.variables.get("user"));
+/// assert!(placeables.select_keys["count"].contains("one"));
+/// assert_eq!(vec!["title"], placeables.attributes);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Placeables<'a> {
+    /// Referenced variables and the kinds of values they are expected to hold.
+    pub variables: BTreeMap<&'a str, Kind>,
+    /// Variant keys of select expressions, keyed by the variables used in their
+    /// selectors.
+    pub select_keys: BTreeMap<&'a str, BTreeSet<&'a str>>,
+    /// Identifiers of referenced messages.
+    pub messages: BTreeSet<&'a str>,
+    /// Identifiers of referenced terms, without the leading `-`.
+    pub terms: BTreeSet<&'a str>,
+    /// Names of the attributes of the message, in order of definition.
+    pub attributes: Vec<&'a str>,
+    selector_keys: Option<Vec<&'a str>>,
+}
+
+impl<'a> Placeables<'a> {
+    /// Creates an empty [`Placeables`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Extracts the placeables of the value and attributes of `message`.
+    pub fn from_message(message: &FluentMessage<'a>) -> Self {
+        let mut placeables = Self::new();
+        if let Some(value) = message.value() {
+            placeables.add_pattern(value);
+        }
+        for attribute in message.attributes() {
+            placeables.attributes.push(attribute.id());
+            placeables.add_pattern(attribute.value());
+        }
+        placeables
+    }
+
+    /// Extracts the placeables of `message`, as [`from_message`](Self::from_message),
+    /// and those of the messages it references in turn, looked up by identifier with
+    /// `get_message`.
+    ///
+    /// Referenced messages are formatted with the same arguments, so their variables
+    /// are needed to format `message` too. Their attributes are not listed in
+    /// [`attributes`](Self::attributes). Terms only see the arguments passed to
+    /// them, so they are not followed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::{FluentBundle, FluentResource};
+    /// use fluent_serde::placeables::Placeables;
+    ///
+    /// let ftl = "
+    /// user = { $first } { $last }
+    /// cart = { user } has { $count } items.
+    /// ";
+    /// let resource = FluentResource::try_new(ftl.into()).unwrap();
+    /// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+    /// bundle.add_resource(resource).unwrap();
+    ///
+    /// let cart = bundle.get_message("cart").unwrap();
+    /// let placeables = Placeables::from_message_with_references(&cart, |id| bundle.get_message(id));
+    /// assert_eq!(vec!["count", "first", "last"], placeables.variables.keys().copied().collect::<Vec<_>>());
+    /// ```
+    pub fn from_message_with_references<F>(message: &FluentMessage<'a>, mut get_message: F) -> Self
+    where
+        F: FnMut(&str) -> Option<FluentMessage<'a>>,
+    {
+        let mut placeables = Self::from_message(message);
+        let mut visited = BTreeSet::new();
+        let mut pending = placeables.messages.iter().copied().collect::<Vec<_>>();
+
+        while let Some(id) = pending.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let referenced = match get_message(id) {
+                Some(referenced) => Self::from_message(&referenced),
+                None => continue,
+            };
+
+            pending.extend(referenced.messages.iter().copied());
+            placeables.merge(referenced);
+        }
+        placeables
+    }
+
+    /// Extracts the placeables of `pattern`.
+    pub fn from_pattern(pattern: &'a ast::Pattern<&'a str>) -> Self {
+        let mut placeables = Self::new();
+        placeables.add_pattern(pattern);
+        placeables
+    }
+
+    /// Adds the placeables of `pattern`.
+    pub fn add_pattern(&mut self, pattern: &'a ast::Pattern<&'a str>) {
+        for element in &pattern.elements {
+            if let ast::PatternElement::Placeable { expression } = element {
+                self.expression(expression);
+            }
+        }
+    }
+
+    /// Adds the variables, select keys, and references of `other`.
+    fn merge(&mut self, other: Self) {
+        for (name, kind) in other.variables {
+            let entry = self.variables.entry(name).or_insert(kind);
+            *entry = (*entry).max(kind);
+        }
+        for (name, keys) in other.select_keys {
+            self.select_keys.entry(name).or_default().extend(keys);
+        }
+        self.messages.extend(other.messages);
+        self.terms.extend(other.terms);
+    }
+
+    fn add_variable(&mut self, name: &'a str, kind: Kind) {
+        let entry = self.variables.entry(name).or_insert(kind);
+        *entry = (*entry).max(kind);
+
+        if let Some(keys) = &self.selector_keys {
+            self.select_keys
+                .entry(name)
+                .or_default()
+                .extend(keys.iter().copied());
+        }
+    }
+
+    fn expression(&mut self, expression: &'a ast::Expression<&'a str>) {
+        match expression {
+            ast::Expression::Select { selector, variants } => {
+                let keys = variants
+                    .iter()
+                    .map(|variant| match &variant.key {
+                        ast::VariantKey::Identifier { name } => *name,
+                        ast::VariantKey::NumberLiteral { value } => *value,
+                    })
+                    .collect::<Vec<_>>();
+                let numeric = variants.iter().any(|variant| match &variant.key {
+                    ast::VariantKey::NumberLiteral { .. } => true,
+                    ast::VariantKey::Identifier { name } => {
+                        matches!(*name, "zero" | "one" | "two" | "few" | "many")
+                    }
+                });
+
+                let outer = self.selector_keys.replace(keys);
+                self.inline(selector, if numeric { Kind::Number } else { Kind::String });
+                self.selector_keys = outer;
+
+                for variant in variants {
+                    self.add_pattern(&variant.value);
+                }
+            }
+            ast::Expression::Inline(inline) => self.inline(inline, Kind::String),
+        }
+    }
+
+    fn inline(&mut self, inline: &'a ast::InlineExpression<&'a str>, kind: Kind) {
+        match inline {
+            ast::InlineExpression::VariableReference { id } => self.add_variable(id.name, kind),
+            ast::InlineExpression::FunctionReference { id, arguments } => {
+                let kind = if id.name == "NUMBER" {
+                    Kind::Number
+                } else {
+                    Kind::String
+                };
+                self.arguments(arguments, kind);
+            }
+            ast::InlineExpression::TermReference { id, arguments, .. } => {
+                self.terms.insert(id.name);
+                if let Some(arguments) = arguments {
+                    self.arguments(arguments, Kind::String);
+                }
+            }
+            ast::InlineExpression::MessageReference { id, .. } => {
+                self.messages.insert(id.name);
+            }
+            ast::InlineExpression::Placeable { expression } => {
+                let outer = self.selector_keys.take();
+                self.expression(expression);
+                self.selector_keys = outer;
+            }
+            ast::InlineExpression::StringLiteral { .. }
+            | ast::InlineExpression::NumberLiteral { .. } => {}
+        }
+    }
+
+    fn arguments(&mut self, arguments: &'a ast::CallArguments<&'a str>, kind: Kind) {
+        for positional in &arguments.positional {
+            self.inline(positional, kind);
+        }
+
+        let outer = self.selector_keys.take();
+        for named in &arguments.named {
+            self.inline(&named.value, Kind::String);
+        }
+        self.selector_keys = outer;
+    }
+}
@@ -0,0 +1,41 @@
+//! Parallel serialization with `rayon`. Requires the `rayon` feature.
+
+use ::rayon::prelude::*;
+use fluent::FluentArgs;
+use serde::Serialize;
+
+use crate::ser::{self, SerializerOptions};
+
+/// Serializes each of `values` into [`FluentArgs`] in parallel, on the global
+/// `rayon` thread pool. Results are in the same order as `values`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::rayon::par_to_args_batch;
+/// use fluent_serde::ser::SerializerOptions;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     name: String,
+/// }
+///
+/// let values = vec![Args { name: "Alice".into() }, Args { name: "Bob".into() }];
+/// let args = par_to_args_batch(&values, SerializerOptions::new());
+///
+/// assert_eq!(Some(&FluentValue::from("Bob")), args[1].as_ref().unwrap().get("name"));
+/// ```
+pub fn par_to_args_batch<T>(
+    values: &[T],
+    options: SerializerOptions,
+) -> Vec<Result<FluentArgs<'static>, ser::Error>>
+where
+    T: Serialize + Sync,
+{
+    values
+        .par_iter()
+        .map(|value| ser::to_args_with(value, options))
+        .collect()
+}
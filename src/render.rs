@@ -0,0 +1,76 @@
+//! Rendering of arguments as plain strings, for consumers that cannot use
+//! [`FluentValue`]s, such as template engines and debugging views.
+
+use std::collections::HashMap;
+
+use fluent::{FluentArgs, FluentValue};
+
+/// Renders each argument as a display string, keyed by argument name.
+///
+/// Values are rendered as a bundle would without a formatter: strings as they are,
+/// numbers with their [`FluentNumberOptions`](fluent::types::FluentNumberOptions)
+/// applied, and [`FluentValue::None`] and [`FluentValue::Error`] as empty strings.
+/// [`FluentValue::Custom`] values can only be formatted with a bundle's memoizer,
+/// so they are rendered as empty strings too. Use [`render_args_with`] to format
+/// them, or to format numbers for a locale.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentArgs;
+/// use fluent_serde::render::render_args;
+///
+/// let mut args = FluentArgs::new();
+/// args.set("name", "Alice");
+/// args.set("count", 3);
+///
+/// let strings = render_args(&args);
+/// assert_eq!("Alice", strings["name"]);
+/// assert_eq!("3", strings["count"]);
+/// ```
+pub fn render_args(args: &FluentArgs<'_>) -> HashMap<String, String> {
+    render_args_with(args, |_| None)
+}
+
+/// Renders each argument as a display string, keyed by argument name, trying
+/// `formatter` first for each value.
+///
+/// Like [`FluentBundle::set_formatter`](fluent::bundle::FluentBundle::set_formatter),
+/// values for which `formatter` returns `None` are rendered as in [`render_args`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentArgs, FluentValue};
+/// use fluent_serde::render::render_args_with;
+///
+/// let mut args = FluentArgs::new();
+/// args.set("name", "Alice");
+/// args.set("total", 1234.5);
+///
+/// let strings = render_args_with(&args, |value| match value {
+///     FluentValue::Number(n) => Some(format!("{:.2}", n.value).replace('.', ",")),
+///     _ => None,
+/// });
+/// assert_eq!("Alice", strings["name"]);
+/// assert_eq!("1234,50", strings["total"]);
+/// ```
+pub fn render_args_with<F>(args: &FluentArgs<'_>, mut formatter: F) -> HashMap<String, String>
+where
+    F: FnMut(&FluentValue<'_>) -> Option<String>,
+{
+    args.iter()
+        .map(|(key, value)| {
+            let text = formatter(value).unwrap_or_else(|| render_value(value));
+            (key.to_string(), text)
+        })
+        .collect()
+}
+
+pub(crate) fn render_value(value: &FluentValue<'_>) -> String {
+    match value {
+        FluentValue::String(s) => s.to_string(),
+        FluentValue::Number(n) => n.as_string().into_owned(),
+        FluentValue::Custom(_) | FluentValue::None | FluentValue::Error => String::new(),
+    }
+}
@@ -0,0 +1,569 @@
+//! Locale-aware resolution of serialized messages.
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use fluent::memoizer::MemoizerKind;
+use fluent::resolver::errors::ReferenceKind;
+use fluent::resolver::ResolverError;
+use fluent::{FluentArgs, FluentBundle, FluentError, FluentResource};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use unic_langid::LanguageIdentifier;
+
+use crate::canonical;
+use crate::ser::{self, ArgsSerializer};
+
+/// A message identifier together with its arguments.
+///
+/// Arguments can be any type supported by [`ArgsSerializer`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Message<T> {
+    /// Identifier of the message in the Fluent resources.
+    pub id: String,
+    /// Arguments of the message.
+    pub args: T,
+}
+
+impl<T> Message<T> {
+    /// Creates a new [`Message`].
+    pub fn new(id: impl Into<String>, args: T) -> Self {
+        Message {
+            id: id.into(),
+            args,
+        }
+    }
+}
+
+/// Arguments built by a closure only when they are serialized, to avoid computing
+/// arguments for messages that are never formatted.
+///
+/// [`Resolver::resolve`] and [`BundleExt::format`] serialize arguments only once
+/// the message is found, so the closure is not called for missing messages. It may
+/// be called more than once if the arguments are serialized more than once.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::Cell;
+///
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::resolver::{BundleExt, LazyArgs};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     count: usize,
+/// }
+///
+/// let resource = FluentResource::try_new("items = { $count } items".into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let calls = Cell::new(0);
+/// let args = LazyArgs::new(|| {
+///     calls.set(calls.get() + 1);
+///     Args { count: 3 }
+/// });
+///
+/// assert!(bundle.format("missing", &args).is_err());
+/// assert_eq!(0, calls.get());
+///
+/// assert_eq!("3 items", bundle.format("items", &args).unwrap());
+/// assert_eq!(1, calls.get());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LazyArgs<F>(F);
+
+impl<F> LazyArgs<F> {
+    /// Creates arguments built by `f` when serialized.
+    pub fn new<T>(f: F) -> Self
+    where
+        F: Fn() -> T,
+        T: Serialize,
+    {
+        LazyArgs(f)
+    }
+}
+
+impl<F, T> Serialize for LazyArgs<F>
+where
+    F: Fn() -> T,
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (self.0)().serialize(serializer)
+    }
+}
+
+/// Resolution error.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ResolveError {
+    #[error("no bundle is registered for locale `{0}` or any of its fallbacks")]
+    MissingLocale(LanguageIdentifier),
+    #[error("message `{0}` does not exist in any candidate locale")]
+    MissingMessage(String),
+    #[error("message `{0}` has no value")]
+    NoValue(String),
+    #[error("message `{id}` references missing arguments: {}", .args.join(", "))]
+    MissingArgs { id: String, args: Vec<String> },
+    #[error("failed to serialize arguments: {0}")]
+    Args(#[from] ser::Error),
+    #[error("errors occurred while formatting message `{id}`")]
+    Format {
+        id: String,
+        errors: Vec<FluentError>,
+    },
+}
+
+impl ResolveError {
+    pub(crate) fn from_format_errors(id: &str, errors: Vec<FluentError>) -> Self {
+        let mut args = Vec::new();
+
+        for error in &errors {
+            match error {
+                FluentError::ResolverError(ResolverError::Reference(ReferenceKind::Variable {
+                    id,
+                })) => args.push(id.clone()),
+                _ => {
+                    return ResolveError::Format {
+                        id: id.to_string(),
+                        errors,
+                    }
+                }
+            }
+        }
+
+        ResolveError::MissingArgs {
+            id: id.to_string(),
+            args,
+        }
+    }
+}
+
+/// Formats message `id` from `bundle` with the arguments built by `args`, which is
+/// only called once the message is found. `errors` is cleared first, and only used
+/// as a buffer.
+pub(crate) fn format_message<R, M, F>(
+    bundle: &fluent::bundle::FluentBundle<R, M>,
+    id: &str,
+    args: F,
+    errors: &mut Vec<FluentError>,
+) -> Result<String, ResolveError>
+where
+    R: Borrow<FluentResource>,
+    M: MemoizerKind,
+    F: FnOnce() -> Result<FluentArgs<'static>, ResolveError>,
+{
+    let message = bundle
+        .get_message(id)
+        .ok_or_else(|| ResolveError::MissingMessage(id.to_string()))?;
+    let pattern = message
+        .value()
+        .ok_or_else(|| ResolveError::NoValue(id.to_string()))?;
+
+    let args = args()?;
+    errors.clear();
+    let text = bundle.format_pattern(pattern, Some(&args), errors);
+    if errors.is_empty() {
+        Ok(text.into_owned())
+    } else {
+        Err(ResolveError::from_format_errors(id, std::mem::take(errors)))
+    }
+}
+
+/// Formats [`Message`]s against a registry of [`FluentBundle`]s keyed by locale.
+///
+/// Candidate bundles for a requested locale are tried in order:
+///
+/// - The bundle registered for the exact locale.
+/// - The bundle registered for the language alone, e.g. `en` for `en-US`.
+/// - The bundles for the fallback locales, in the order they were set.
+///
+/// The first candidate that contains the message is used to format it.
+///
+/// Bundles may hold resources of any type that borrows as a [`FluentResource`]. This
+/// includes bundles of `&FluentResource` handed out by resource managers such as
+/// `fluent-resmgr`, which can be registered as they are:
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::resolver::{Message, Resolver};
+/// use unic_langid::LanguageIdentifier;
+///
+/// // Stands in for resources owned by a resource manager.
+/// let resource = FluentResource::try_new("bye = Goodbye!".into()).unwrap();
+///
+/// let en: LanguageIdentifier = "en".parse().unwrap();
+/// let mut bundle: FluentBundle<&FluentResource> = FluentBundle::new(vec![en.clone()]);
+/// bundle.add_resource(&resource).unwrap();
+///
+/// let mut resolver = Resolver::new();
+/// resolver.add_bundle(bundle);
+/// assert_eq!("Goodbye!", resolver.resolve(&Message::new("bye", ()), &en).unwrap());
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::resolver::{Message, Resolver};
+/// use serde::Serialize;
+/// use unic_langid::LanguageIdentifier;
+///
+/// #[derive(Serialize)]
+/// struct Greeting {
+///     name: String,
+/// }
+///
+/// let en: LanguageIdentifier = "en".parse().unwrap();
+/// let resource = FluentResource::try_new("hello = Hello, { $name }!".into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec![en.clone()]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let mut resolver = Resolver::new();
+/// resolver.add_bundle(bundle);
+/// resolver.set_fallbacks(vec![en]);
+///
+/// let message = Message::new("hello", Greeting { name: "Alice".into() });
+/// let text = resolver.resolve(&message, &"fr-FR".parse().unwrap()).unwrap();
+/// assert_eq!("Hello, Alice!", text);
+/// ```
+pub struct Resolver<R = FluentResource> {
+    bundles: HashMap<LanguageIdentifier, FluentBundle<R>>,
+    fallbacks: Vec<LanguageIdentifier>,
+}
+
+impl<R> Default for Resolver<R> {
+    fn default() -> Self {
+        Resolver {
+            bundles: HashMap::new(),
+            fallbacks: Vec::new(),
+        }
+    }
+}
+
+impl<R> Resolver<R> {
+    /// Creates an empty [`Resolver`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a bundle under its first locale, replacing any bundle previously
+    /// registered for it. Bundles without locales are registered under the default
+    /// [`LanguageIdentifier`].
+    pub fn add_bundle(&mut self, bundle: FluentBundle<R>) -> Option<FluentBundle<R>> {
+        let locale = bundle.locales.first().cloned().unwrap_or_default();
+        self.bundles.insert(locale, bundle)
+    }
+
+    /// Returns the bundle registered for the exact locale, if any.
+    pub fn bundle(&self, locale: &LanguageIdentifier) -> Option<&FluentBundle<R>> {
+        self.bundles.get(locale)
+    }
+
+    /// Sets the locales tried, in order, when a message cannot be found in the
+    /// requested locale.
+    pub fn set_fallbacks(&mut self, fallbacks: Vec<LanguageIdentifier>) {
+        self.fallbacks = fallbacks;
+    }
+
+    fn candidates(&self, locale: &LanguageIdentifier) -> Vec<LanguageIdentifier> {
+        let language = LanguageIdentifier::from_parts(locale.language, None, None, &[]);
+
+        let mut candidates = vec![locale.clone()];
+        for candidate in std::iter::once(language).chain(self.fallbacks.iter().cloned()) {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+impl<R> Resolver<R>
+where
+    R: Borrow<FluentResource>,
+{
+    /// Formats `message` in `locale`, falling back to other candidate locales if
+    /// necessary. Arguments are serialized only once a candidate containing the
+    /// message is found.
+    pub fn resolve<T>(
+        &self,
+        message: &Message<T>,
+        locale: &LanguageIdentifier,
+    ) -> Result<String, ResolveError>
+    where
+        T: Serialize,
+    {
+        let args = || Ok(ser::to_args(&message.args)?);
+        self.resolve_with(&message.id, locale, args)
+    }
+
+    fn resolve_with<F>(
+        &self,
+        id: &str,
+        locale: &LanguageIdentifier,
+        args: F,
+    ) -> Result<String, ResolveError>
+    where
+        F: FnOnce() -> Result<FluentArgs<'static>, ResolveError>,
+    {
+        let mut any_bundle = false;
+
+        for candidate in self.candidates(locale) {
+            let bundle = match self.bundles.get(&candidate) {
+                Some(bundle) => bundle,
+                None => continue,
+            };
+            any_bundle = true;
+
+            if bundle.has_message(id) {
+                return format_message(bundle, id, args, &mut Vec::new());
+            }
+        }
+
+        if any_bundle {
+            Err(ResolveError::MissingMessage(id.to_string()))
+        } else {
+            Err(ResolveError::MissingLocale(locale.clone()))
+        }
+    }
+}
+
+/// Locale, message identifier, and canonical arguments of a cached string.
+type CacheKey = (LanguageIdentifier, String, String);
+
+/// Caches strings formatted by a [`Resolver`], keyed by locale, message
+/// identifier, and the [canonical form](crate::canonical) of the arguments, for
+/// services rendering the same messages with the same arguments repeatedly.
+///
+/// Arguments are still serialized on each call, to look up the cache. Only
+/// successfully formatted strings are cached. When the cache is full, the oldest
+/// string is evicted to make room for the next one. Cached strings are not
+/// invalidated when bundles change, so [`clear`](FormatCache::clear) the cache
+/// when they do.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::resolver::{FormatCache, Message, Resolver};
+/// use serde::Serialize;
+/// use unic_langid::LanguageIdentifier;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     count: u32,
+/// }
+///
+/// let en: LanguageIdentifier = "en".parse().unwrap();
+/// let resource = FluentResource::try_new("unread = { $count } unread".into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec![en.clone()]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let mut resolver = Resolver::new();
+/// resolver.add_bundle(bundle);
+///
+/// let mut cache = FormatCache::new(1000).ttl(Duration::from_secs(60));
+/// for _ in 0..3 {
+///     let message = Message::new("unread", Args { count: 2 });
+///     assert_eq!("2 unread", cache.resolve(&resolver, &message, &en).unwrap());
+/// }
+/// assert_eq!(1, cache.len());
+/// ```
+#[derive(Debug)]
+pub struct FormatCache {
+    entries: HashMap<CacheKey, (String, Instant)>,
+    /// Keys of `entries`, oldest first.
+    order: VecDeque<CacheKey>,
+    max_entries: usize,
+    ttl: Option<Duration>,
+}
+
+impl FormatCache {
+    /// Creates an empty cache holding at most `max_entries` strings.
+    pub fn new(max_entries: usize) -> Self {
+        FormatCache {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            max_entries,
+            ttl: None,
+        }
+    }
+
+    /// Sets how long cached strings are used for. By default, they do not expire.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Returns the number of cached strings, including expired ones not yet
+    /// removed.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Empties the cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// Formats `message` in `locale` with `resolver`, as [`Resolver::resolve`], or
+    /// returns the cached string.
+    ///
+    /// Arguments without a canonical form, such as custom values, are formatted
+    /// without caching.
+    pub fn resolve<R, T>(
+        &mut self,
+        resolver: &Resolver<R>,
+        message: &Message<T>,
+        locale: &LanguageIdentifier,
+    ) -> Result<String, ResolveError>
+    where
+        R: Borrow<FluentResource>,
+        T: Serialize,
+    {
+        let args = ser::to_args(&message.args)?;
+
+        let canonical = match canonical::to_canonical_string(&args) {
+            Ok(canonical) => canonical,
+            Err(_) => return resolver.resolve_with(&message.id, locale, || Ok(args)),
+        };
+        let key = (locale.clone(), message.id.clone(), canonical);
+
+        let now = Instant::now();
+        if let Some((text, inserted)) = self.entries.get(&key) {
+            if !is_expired(self.ttl, *inserted, now) {
+                return Ok(text.clone());
+            }
+        }
+
+        let text = resolver.resolve_with(&message.id, locale, || Ok(args))?;
+        if self.max_entries > 0 {
+            // An expired string being replaced moves to the back.
+            if self.entries.remove(&key).is_some() {
+                self.order.retain(|k| *k != key);
+            }
+            // Strings expire in insertion order, so the oldest is evicted first
+            // whether it has expired or not.
+            while self.entries.len() >= self.max_entries {
+                let oldest = match self.order.pop_front() {
+                    Some(oldest) => oldest,
+                    None => break,
+                };
+                self.entries.remove(&oldest);
+            }
+            self.order.push_back(key.clone());
+            self.entries.insert(key, (text.clone(), now));
+        }
+        Ok(text)
+    }
+}
+
+fn is_expired(ttl: Option<Duration>, inserted: Instant, now: Instant) -> bool {
+    ttl.is_some_and(|ttl| now - inserted >= ttl)
+}
+
+/// Extension trait for formatting messages from a [`FluentBundle`] with serialized
+/// arguments.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::resolver::BundleExt;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     name: String,
+/// }
+///
+/// let resource = FluentResource::try_new(
+///     "hello = Hello, { $name }!\nbye = Goodbye, { $name }!".into(),
+/// ).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let alice = Args { name: "Alice".into() };
+/// let bob = Args { name: "Bob".into() };
+///
+/// assert_eq!("Hello, Alice!", bundle.format("hello", &alice).unwrap());
+///
+/// let texts = bundle.format_many(vec![("hello", &alice), ("bye", &bob)]);
+/// let texts = texts.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(vec!["Hello, Alice!", "Goodbye, Bob!"], texts);
+/// ```
+pub trait BundleExt {
+    /// Formats the value of message `id`, with arguments serialized from `args`.
+    /// Arguments are serialized only if the message exists and has a value.
+    fn format<T>(&self, id: &str, args: &T) -> Result<String, ResolveError>
+    where
+        T: ?Sized + Serialize;
+
+    /// Formats the value of each message in `messages`, with arguments serialized
+    /// from the paired value.
+    ///
+    /// One serializer and error buffer are reused across all messages, which makes
+    /// this cheaper than calling [`format`](BundleExt::format) in a loop when
+    /// formatting many messages at once. Failures are reported per message.
+    fn format_many<'a, I, T>(&self, messages: I) -> Vec<Result<String, ResolveError>>
+    where
+        I: IntoIterator<Item = (&'a str, &'a T)>,
+        T: 'a + ?Sized + Serialize;
+}
+
+impl<R, M> BundleExt for fluent::bundle::FluentBundle<R, M>
+where
+    R: Borrow<FluentResource>,
+    M: MemoizerKind,
+{
+    fn format<T>(&self, id: &str, args: &T) -> Result<String, ResolveError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let args = || Ok(ser::to_args(args)?);
+        format_message(self, id, args, &mut Vec::new())
+    }
+
+    fn format_many<'a, I, T>(&self, messages: I) -> Vec<Result<String, ResolveError>>
+    where
+        I: IntoIterator<Item = (&'a str, &'a T)>,
+        T: 'a + ?Sized + Serialize,
+    {
+        let messages = messages.into_iter();
+        let mut texts = Vec::with_capacity(messages.size_hint().0);
+        let mut ser = ArgsSerializer::new();
+        let mut errors = Vec::new();
+
+        for (id, args) in messages {
+            let args = || {
+                let result = args.serialize(&mut ser);
+                let args = ser.take();
+                result?;
+                Ok(args)
+            };
+            texts.push(format_message(self, id, args, &mut errors));
+        }
+
+        texts
+    }
+}
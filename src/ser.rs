@@ -1,29 +1,269 @@
 //! Serialization.
 
+use std::fmt;
+
 use thiserror::Error;
 
-mod unsupported;
+/// Logs a diagnostic message with the given `log` macro, if the `log` feature is
+/// enabled.
+macro_rules! diag {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        ::log::$level!($($arg)+);
+    };
+}
+
+pub(crate) mod key;
+pub(crate) mod unsupported;
 
 pub mod args;
+pub mod cache;
+pub mod intern;
+pub mod lint;
+pub mod metrics;
+pub mod observer;
+pub mod options;
+pub mod sink;
 pub mod value;
 
-pub use args::ArgsSerializer;
-pub use value::ValueSerializer;
+pub use args::{to_args, to_args_with, ArgsSerializer, MergeReport, ToFluentArgs};
+pub use cache::ArgsCache;
+pub use intern::KeyInterner;
+pub use lint::{LintIssue, StringLint};
+pub use metrics::ArgsMetrics;
+pub use observer::ArgsObserver;
+pub use options::{PseudoOptions, Raw, ResultPolicy, SerializerOptions, Static};
+pub use sink::ArgSink;
+pub use value::{to_value, IntoFluentValue, ValueSerializer};
 
 /// Serialization error.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum Error {
-    #[error("this type is unsupported")]
-    UnsupportedType,
+    /// A value of an unsupported kind was serialized. `name` is the name of the
+    /// struct or enum, if serde provides it.
+    #[error(
+        "this type is unsupported: {kind}{}",
+        .name.map(|name| format!(" `{}`", name)).unwrap_or_default()
+    )]
+    UnsupportedType {
+        kind: DataKind,
+        name: Option<&'static str>,
+    },
+    /// No longer returned: [`ValueSerializer`] is consumed by each value, so it
+    /// cannot be used twice. Kept for compatibility.
     #[error("this serializer is already used")]
     AlreadyUsed,
-    #[error("input bytes do not form a valid UTF-8 encoded string")]
-    NonUtf8Bytes,
+    /// Bytes are not valid UTF-8. `offset` is the position of the first invalid
+    /// sequence, and `preview` the start of the bytes, with invalid sequences
+    /// replaced.
+    #[error(
+        "input bytes do not form a valid UTF-8 encoded string: invalid sequence at offset {offset} in \"{preview}\""
+    )]
+    NonUtf8Bytes { offset: usize, preview: String },
     #[error("invalid call sequence of map serialization methods")]
     InvalidSerMap,
     #[error("{0}")]
     Custom(String),
+    /// A number cannot be represented exactly as `target`, and
+    /// [`SerializerOptions::strict_numbers`] or [`SerializerOptions::strict`] is
+    /// set.
+    #[error("{value} cannot be represented exactly as {target}")]
+    PrecisionLoss { value: String, target: &'static str },
+    /// A value of `kind` would be converted to a different kind, and
+    /// [`SerializerOptions::strict`] is set.
+    #[error("strict mode forbids converting a {kind} value")]
+    Conversion { kind: DataKind },
+    /// A map key has no representation as an argument name. `key` is the key
+    /// rendered as text, with the contents of compound keys elided, and `kind` its
+    /// kind.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// use fluent_serde::ser::{ArgsSerializer, DataKind, Error};
+    /// use serde::Serialize;
+    ///
+    /// let mut args = BTreeMap::new();
+    /// args.insert((1, 2), "pair");
+    ///
+    /// let err = args.serialize(&mut ArgsSerializer::new()).unwrap_err();
+    /// assert!(matches!(
+    ///     err,
+    ///     Error::InvalidKey { key, kind: DataKind::Tuple } if key == "(..)"
+    /// ));
+    /// ```
+    #[error("map key `{key}` is a {kind}, which cannot be an argument name")]
+    InvalidKey { key: String, kind: DataKind },
+    /// The value is the `Err` variant of a [`Result`], rendered as `message`, and
+    /// [`SerializerOptions::results`] leaves it out or moves it to another argument.
+    /// [`ArgsSerializer`] handles this error itself.
+    #[error("value is an error: {message}")]
+    ResultErr { message: String },
+    /// Serializing the value of an argument failed.
+    #[error("failed to serialize argument `{key}`: {source}")]
+    Arg {
+        key: String,
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Returns the name of the argument that failed to serialize, if known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_serde::ser::ArgsSerializer;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Args {
+    ///     name: String,
+    ///     tags: Vec<String>,
+    /// }
+    ///
+    /// let args = Args { name: "Alice".into(), tags: vec![] };
+    /// let err = args.serialize(&mut ArgsSerializer::new()).unwrap_err();
+    /// assert_eq!(Some("tags"), err.key());
+    /// ```
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            Error::Arg { key, .. } => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Returns an [`Error::NonUtf8Bytes`] for `bytes`, which failed to decode with
+    /// `err`.
+    pub(crate) fn non_utf8_bytes(bytes: &[u8], err: std::str::Utf8Error) -> Self {
+        const PREVIEW_LEN: usize = 32;
+
+        let mut preview =
+            String::from_utf8_lossy(&bytes[..bytes.len().min(PREVIEW_LEN)]).into_owned();
+        if bytes.len() > PREVIEW_LEN {
+            preview.push('…');
+        }
+        Error::NonUtf8Bytes {
+            offset: err.valid_up_to(),
+            preview,
+        }
+    }
+
+    /// Returns the underlying error, without the argument name.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Arg { source, .. } => source.root(),
+            err => err,
+        }
+    }
+
+    /// Returns the category of the underlying error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// use fluent_serde::ser::{ArgsSerializer, ErrorKind};
+    /// use serde::Serialize;
+    ///
+    /// let mut args = BTreeMap::new();
+    /// args.insert(None::<u32>, "none");
+    ///
+    /// let err = args.serialize(&mut ArgsSerializer::new()).unwrap_err();
+    /// assert_eq!(ErrorKind::InvalidKey, err.kind());
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self.root() {
+            Error::UnsupportedType { .. } | Error::AlreadyUsed => ErrorKind::Unsupported,
+            Error::NonUtf8Bytes { .. } => ErrorKind::InvalidUtf8,
+            Error::InvalidSerMap => ErrorKind::InvalidMap,
+            Error::Custom(_) => ErrorKind::Custom,
+            Error::PrecisionLoss { .. } => ErrorKind::PrecisionLoss,
+            Error::InvalidKey { .. } => ErrorKind::InvalidKey,
+            Error::Conversion { .. } => ErrorKind::Conversion,
+            Error::ResultErr { .. } => ErrorKind::ResultErr,
+            Error::Arg { .. } => unreachable!("root errors are never `Arg`"),
+        }
+    }
+}
+
+/// Category of an [`Error`], returned by [`Error::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A value of an unsupported type was serialized.
+    Unsupported,
+    /// A byte string is not valid UTF-8.
+    InvalidUtf8,
+    /// Map serialization methods were called out of order.
+    InvalidMap,
+    /// A map key cannot be an argument name.
+    InvalidKey,
+    /// A number cannot be represented exactly.
+    PrecisionLoss,
+    /// A value would be converted to a different kind in strict mode.
+    Conversion,
+    /// A `Serialize` implementation reported an error.
+    Custom,
+    /// A [`Result`] is an `Err`, which is not serialized as a value.
+    ResultErr,
+}
+
+/// Kind of value in the `serde` data model, reported by
+/// [`Error::UnsupportedType`] and [`Error::InvalidKey`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataKind {
+    Bool,
+    Number,
+    Char,
+    Str,
+    Bytes,
+    None,
+    Some,
+    Unit,
+    UnitStruct,
+    UnitVariant,
+    NewtypeStruct,
+    NewtypeVariant,
+    Seq,
+    Tuple,
+    TupleStruct,
+    TupleVariant,
+    Map,
+    Struct,
+    StructVariant,
+}
+
+impl fmt::Display for DataKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DataKind::Bool => "boolean",
+            DataKind::Number => "number",
+            DataKind::Char => "char",
+            DataKind::Str => "string",
+            DataKind::Bytes => "bytes",
+            DataKind::None => "none",
+            DataKind::Some => "some",
+            DataKind::Unit => "unit",
+            DataKind::UnitStruct => "unit struct",
+            DataKind::UnitVariant => "unit variant",
+            DataKind::NewtypeStruct => "newtype struct",
+            DataKind::NewtypeVariant => "newtype variant",
+            DataKind::Seq => "sequence",
+            DataKind::Tuple => "tuple",
+            DataKind::TupleStruct => "tuple struct",
+            DataKind::TupleVariant => "tuple variant",
+            DataKind::Map => "map",
+            DataKind::Struct => "struct",
+            DataKind::StructVariant => "struct variant",
+        })
+    }
 }
 
 impl serde::ser::Error for Error {
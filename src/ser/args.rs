@@ -1,22 +1,34 @@
 //! Serializer for [`FluentArgs`].
 
 use std::borrow::Cow;
+use std::iter::FromIterator;
 
 use fluent::{FluentArgs, FluentValue};
 use serde::ser::{SerializeMap, SerializeStruct, SerializeStructVariant};
 use serde::Serializer;
 
+use super::key::KeySerializer;
 use super::unsupported::Unsupported;
-use super::{Error, ValueSerializer};
+use super::{
+    ArgSink, ArgsMetrics, ArgsObserver, DataKind, Error, KeyInterner, ResultPolicy,
+    SerializerOptions, Static, StringLint, ValueSerializer,
+};
 
 /// Serialize into a [`FluentArgs`]. Can be used multiple times to merge structures.
 ///
 /// The supported types are:
 ///
-/// - Maps from strings to [`ValueSerializer`] types.
+/// - Maps to [`ValueSerializer`] types, with keys that are strings, numbers, or
+///   types formatted as strings, such as UUIDs.
 /// - Structures of [`ValueSerializer`] types.
 /// - [`Option`]s and newtypes of supported types.
 ///
+/// Values may be transformed according to [`SerializerOptions`]. Argument names
+/// are never transformed.
+///
+/// Arguments are collected into a [`FluentArgs`], or into another container
+/// implementing [`ArgSink`], given to [`with_sink`](Self::with_sink).
+///
 /// See also [`ValueSerializer`](crate::ser::ValueSerializer).
 ///
 /// # Example
@@ -40,8 +52,8 @@ use super::{Error, ValueSerializer};
 /// }
 ///
 /// let mut ser = ArgsSerializer::new();
-/// Foo { foo: 42 }.serialize(&mut ser);
-/// Bar { bar: "bar".into() }.serialize(&mut ser);
+/// ser.merge_from(&Foo { foo: 42 }).unwrap();
+/// ser.merge_from(&Bar { bar: "bar".into() }).unwrap();
 /// let args = ser.done();
 ///
 /// assert_eq!(
@@ -55,8 +67,21 @@ use super::{Error, ValueSerializer};
 /// );
 /// ```
 #[derive(Default)]
-pub struct ArgsSerializer {
-    args: FluentArgs<'static>,
+pub struct ArgsSerializer<S = FluentArgs<'static>> {
+    args: S,
+    options: SerializerOptions,
+    lint: Option<StringLint>,
+    observers: Vec<Box<dyn ArgsObserver>>,
+    metrics: Option<ArgsMetrics>,
+    interner: Option<KeyInterner>,
+    /// Number of arguments `args` has room for, as far as known.
+    capacity: usize,
+    /// Errors recorded instead of returned, if collecting.
+    errors: Option<Vec<Error>>,
+    /// Overwritten arguments, while merging with a report.
+    overwritten: Option<Vec<(String, FluentValue<'static>)>>,
+    /// Whether values are only classified, as by [`describe`](crate::describe).
+    kind_only: bool,
 }
 
 impl ArgsSerializer {
@@ -65,15 +90,618 @@ impl ArgsSerializer {
         ArgsSerializer::default()
     }
 
+    /// Creates a new [`ArgsSerializer`] with `options` applied to all values.
+    pub fn with_options(options: SerializerOptions) -> Self {
+        ArgsSerializer {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`ArgsSerializer`] with room for `capacity` arguments.
+    ///
+    /// Without this, room is reserved for the number of fields of the first
+    /// structure or map serialized, when the length is known.
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArgsSerializer {
+            args: FluentArgs::with_capacity(capacity),
+            capacity,
+            ..Self::default()
+        }
+    }
+
     /// Creates an [`ArgsSerializer`] based on an existing argument map.
     pub fn from_existing(args: FluentArgs<'static>) -> Self {
-        ArgsSerializer { args }
+        ArgsSerializer {
+            args,
+            ..Self::default()
+        }
+    }
+}
+
+impl<S> ArgsSerializer<S>
+where
+    S: ArgSink,
+{
+    /// Creates an [`ArgsSerializer`] serializing into `sink`, which can be any
+    /// argument container implementing [`ArgSink`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use std::collections::HashMap;
+    ///
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::{ArgSink, ArgsSerializer};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Default)]
+    /// struct Pool(HashMap<Cow<'static, str>, FluentValue<'static>>);
+    ///
+    /// impl ArgSink for Pool {
+    ///     fn set(&mut self, key: Cow<'static, str>, value: FluentValue<'static>) {
+    ///         self.0.insert(key, value);
+    ///     }
+    /// }
+    ///
+    /// #[derive(Serialize)]
+    /// struct Args {
+    ///     name: String,
+    /// }
+    ///
+    /// let mut ser = ArgsSerializer::with_sink(Pool::default());
+    /// ser.merge_from(&Args { name: "Alice".into() }).unwrap();
+    /// let pool = ser.into_sink();
+    ///
+    /// assert_eq!(Some(&FluentValue::from("Alice")), pool.0.get("name"));
+    /// ```
+    pub fn with_sink(sink: S) -> Self {
+        ArgsSerializer {
+            args: sink,
+            options: SerializerOptions::default(),
+            lint: None,
+            observers: Vec::new(),
+            metrics: None,
+            interner: None,
+            capacity: 0,
+            errors: None,
+            overwritten: None,
+            kind_only: false,
+        }
+    }
+
+    /// Returns the argument container serialized into.
+    pub fn into_sink(self) -> S {
+        self.args
+    }
+
+    /// Returns the options of the serializer.
+    pub fn options(&self) -> &SerializerOptions {
+        &self.options
+    }
+
+    /// Sets the options applied to values serialized from now on.
+    pub fn set_options(&mut self, options: SerializerOptions) {
+        self.options = options;
+    }
+
+    /// Sets a lint checking string values serialized from now on.
+    pub fn set_lint(&mut self, lint: StringLint) {
+        self.lint = Some(lint);
+    }
+
+    /// Adds an observer notified of insertions and errors from now on. Observers are
+    /// called in the order they were added.
+    pub fn add_observer<O>(&mut self, observer: O)
+    where
+        O: ArgsObserver + 'static,
+    {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Records the work done from now on in `metrics`.
+    pub fn set_metrics(&mut self, metrics: &ArgsMetrics) {
+        self.metrics = Some(metrics.clone());
+    }
+
+    /// Interns map keys serialized from now on in `interner`.
+    pub fn set_key_interner(&mut self, interner: &KeyInterner) {
+        self.interner = Some(interner.clone());
+    }
+
+    /// Inserts a static string argument without copying it, as with [`Static`].
+    /// Options, lint, observers, and metrics apply as for serialized values.
+    pub fn insert_static(&mut self, key: &'static str, value: &'static str) {
+        // Serializing a `Static` cannot fail.
+        let _ = self.insert_value(Cow::Borrowed(key), &Static(value));
+    }
+
+    /// Serializes a single `value` and inserts it under `key`. Options, lint,
+    /// observers, and metrics apply as for serialized structs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::ArgsSerializer;
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.insert("request-id", &42).unwrap();
+    /// let args = ser.done();
+    ///
+    /// assert_eq!(Some(&FluentValue::from(42)), args.get("request-id"));
+    /// ```
+    pub fn insert<K, T>(&mut self, key: K, value: &T) -> Result<(), Error>
+    where
+        K: Into<Cow<'static, str>>,
+        T: ?Sized + serde::Serialize,
+    {
+        self.insert_value(key.into(), value)
+    }
+
+    /// Serializes `value` into the arguments, overwriting arguments with the same
+    /// names. This is the same as `value.serialize(&mut ser)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::ArgsSerializer;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(Serialize)]
+    /// struct Cart {
+    ///     count: u32,
+    /// }
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.merge_from(&User { name: "Alice".into() })?;
+    /// ser.merge_from(&Cart { count: 3 })?;
+    /// let args = ser.done();
+    ///
+    /// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+    /// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+    /// # Ok::<(), fluent_serde::ser::Error>(())
+    /// ```
+    pub fn merge_from<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        value.serialize(self)
+    }
+
+    /// Serializes a single `value` and inserts it under `key`, as
+    /// [`insert`](Self::insert), returning the serializer for chaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::ArgsSerializer;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// let args = ArgsSerializer::new()
+    ///     .with_merged(&User { name: "Alice".into() })?
+    ///     .with_entry("count", &3)?
+    ///     .done();
+    ///
+    /// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+    /// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+    /// # Ok::<(), fluent_serde::ser::Error>(())
+    /// ```
+    pub fn with_entry<K, T>(mut self, key: K, value: &T) -> Result<Self, Error>
+    where
+        K: Into<Cow<'static, str>>,
+        T: ?Sized + serde::Serialize,
+    {
+        self.insert(key, value)?;
+        Ok(self)
+    }
+
+    /// Serializes `value` into the arguments, returning the serializer for
+    /// chaining. See [`with_entry`](Self::with_entry).
+    pub fn with_merged<T>(mut self, value: &T) -> Result<Self, Error>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.merge_from(value)?;
+        Ok(self)
+    }
+
+    /// Serializes `value` into the arguments like [`Serialize::serialize`], and
+    /// reports the arguments it overwrote.
+    ///
+    /// [`Serialize::serialize`]: serde::Serialize::serialize
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::ArgsSerializer;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(Serialize)]
+    /// struct Team {
+    ///     name: String,
+    ///     size: u32,
+    /// }
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.merge_reporting(&User { name: "Alice".into() }).unwrap();
+    /// let report = ser
+    ///     .merge_reporting(&Team { name: "Core".into(), size: 3 })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec![("name".to_string(), FluentValue::from("Alice"))],
+    ///     report.overwritten(),
+    /// );
+    /// ```
+    pub fn merge_reporting<T>(&mut self, value: &T) -> Result<MergeReport, Error>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.overwritten = Some(Vec::new());
+        let result = self.merge_from(value);
+        let overwritten = self.overwritten.take().unwrap_or_default();
+        result.map(|()| MergeReport { overwritten })
+    }
+
+    /// Sets whether errors serializing argument values and map keys are recorded
+    /// instead of returned, so the remaining arguments are still serialized.
+    /// Recorded errors are returned by [`errors`](Self::errors).
+    ///
+    /// Errors for values that cannot be serialized as arguments at all, such as
+    /// sequences, are still returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::ArgsSerializer;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Args {
+    ///     tags: Vec<String>,
+    ///     name: String,
+    /// }
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.set_collect_errors(true);
+    /// Args { tags: vec![], name: "Alice".into() }.serialize(&mut ser).unwrap();
+    ///
+    /// assert_eq!(Some("tags"), ser.errors()[0].key());
+    /// assert_eq!(Some(&FluentValue::from("Alice")), ser.done().get("name"));
+    /// ```
+    pub fn set_collect_errors(&mut self, collect: bool) {
+        if !collect {
+            self.errors = None;
+        } else if self.errors.is_none() {
+            self.errors = Some(Vec::new());
+        }
+    }
+
+    /// Returns the errors recorded while collecting errors.
+    pub fn errors(&self) -> &[Error] {
+        self.errors.as_deref().unwrap_or_default()
+    }
+
+    /// Takes the errors recorded while collecting errors.
+    pub fn take_errors(&mut self) -> Vec<Error> {
+        self.errors.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Makes values empty placeholders of the right kind, for sinks that only
+    /// record kinds.
+    pub(crate) fn set_kind_only(&mut self, kind_only: bool) {
+        self.kind_only = kind_only;
+    }
+
+    fn value_serializer(&self) -> ValueSerializer {
+        ValueSerializer::with_options(self.options)
+            .with_metrics(self.metrics.clone())
+            .kind_only(self.kind_only)
+    }
+
+    /// Makes room for `len` arguments, unless there is room already.
+    fn reserve(&mut self, len: usize) {
+        if len > self.capacity {
+            self.args.reserve(len);
+            self.capacity = len;
+        }
+    }
+
+    fn insert_value<T>(&mut self, key: Cow<'static, str>, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        let value = match value.serialize(self.value_serializer()) {
+            Ok(value) => value,
+            Err(Error::ResultErr { message }) => {
+                if self.options.result_policy() == ResultPolicy::ErrorKey {
+                    let error_key = Cow::Owned(format!("{}-error", key));
+                    let message = self.value_serializer().string_value(|| Cow::Owned(message));
+                    self.insert_fluent_value(error_key, message);
+                    self.insert_fluent_value(key, FluentValue::None);
+                }
+                return Ok(());
+            }
+            Err(err) => {
+                self.notify_error(Some(&key), &err);
+                let err = Error::Arg {
+                    key: key.clone().into_owned(),
+                    source: Box::new(err),
+                };
+                if !self.options.is_errors_as_values() {
+                    return self.fail(err);
+                }
+                if let Some(errors) = &mut self.errors {
+                    errors.push(err);
+                }
+                FluentValue::Error
+            }
+        };
+        self.insert_fluent_value(key, value);
+        Ok(())
+    }
+
+    /// Inserts an already converted `value`, applying lint, observers, and metrics.
+    fn insert_fluent_value(&mut self, key: Cow<'static, str>, mut value: FluentValue<'static>) {
+        if let (Some(lint), FluentValue::String(s)) = (&self.lint, &value) {
+            lint.check(&key, s);
+        }
+
+        for observer in &mut self.observers {
+            observer.on_insert(&key, &mut value);
+        }
+        if !self.observers.is_empty() {
+            if let Some(old) = self.args.get(&key) {
+                for observer in &mut self.observers {
+                    observer.on_overwrite(&key, old, &value);
+                }
+            }
+        }
+
+        if let Some(overwritten) = &mut self.overwritten {
+            if let Some(old) = self.args.get(&key) {
+                overwritten.push((key.clone().into_owned(), old.into_owned()));
+            }
+        }
+
+        #[cfg(feature = "log")]
+        if !self.kind_only {
+            if self.args.get(&key).is_some() {
+                diag!(debug, "overwriting argument `{}` with {:?}", key, value);
+            } else {
+                diag!(trace, "inserting argument `{}`: {:?}", key, value);
+            }
+        }
+
+        if let Some(metrics) = &self.metrics {
+            let string_bytes = match &value {
+                FluentValue::String(s) => s.len(),
+                _ => 0,
+            };
+            metrics.record_arg(string_bytes);
+        }
+
+        self.args.set(key, value);
+    }
+
+    /// Records `err` if collecting errors, or returns it otherwise.
+    fn fail(&mut self, err: Error) -> Result<(), Error> {
+        match &mut self.errors {
+            Some(errors) => {
+                errors.push(err);
+                Ok(())
+            }
+            None => Err(err),
+        }
+    }
+
+    fn notify_error(&mut self, key: Option<&str>, error: &Error) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_failure();
+        }
+
+        for observer in &mut self.observers {
+            observer.on_error(key, error);
+        }
+    }
+}
+
+impl ArgsSerializer {
+    /// Returns the number of arguments serialized so far.
+    pub fn len(&self) -> usize {
+        self.args.iter().count()
+    }
+
+    /// Returns `true` if no arguments have been serialized yet.
+    pub fn is_empty(&self) -> bool {
+        self.args.iter().next().is_none()
+    }
+
+    /// Returns `true` if an argument named `key` has been serialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_serde::ser::ArgsSerializer;
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.insert("name", "Alice").unwrap();
+    ///
+    /// assert!(ser.contains_key("name"));
+    /// assert!(!ser.contains_key("count"));
+    /// assert_eq!(1, ser.len());
+    /// ```
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.args.get(key).is_some()
+    }
+
+    /// Returns an iterator over the arguments serialized so far.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FluentValue<'_>)> {
+        self.args.iter()
+    }
+
+    /// Removes the argument named `key`, returning its value if present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::ArgsSerializer;
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.insert("name", "Alice").unwrap();
+    /// ser.insert("token", "secret").unwrap();
+    ///
+    /// assert_eq!(Some(FluentValue::from("secret")), ser.remove("token"));
+    /// assert_eq!(None, ser.remove("token"));
+    /// assert!(ser.contains_key("name"));
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<FluentValue<'static>> {
+        if !self.contains_key(key) {
+            return None;
+        }
+        // `FluentArgs` cannot remove entries in place, so the rest are moved over.
+        let len = self.len();
+        let mut removed = None;
+        let mut args = FluentArgs::with_capacity(len.max(self.capacity));
+        for (k, value) in std::mem::take(&mut self.args) {
+            if k == key {
+                removed = Some(value);
+            } else {
+                args.set(k, value);
+            }
+        }
+        self.args = args;
+        removed
     }
 
     /// Returns the built [`FluentArgs`] value.
+    ///
+    /// To merge into arguments that borrow from elsewhere, see
+    /// [`merge_into`](Self::merge_into).
     pub fn done(self) -> FluentArgs<'static> {
         self.args
     }
+
+    /// Merges the built arguments into `args`, overwriting arguments with the same
+    /// names. Unlike [`from_existing`](Self::from_existing), `args` can borrow from
+    /// elsewhere, so they are not copied to `'static` first. Observers are not
+    /// notified of arguments overwritten this way.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::{FluentArgs, FluentValue};
+    /// use fluent_serde::ser::ArgsSerializer;
+    ///
+    /// let name = String::from("Alice");
+    /// let mut args = FluentArgs::new();
+    /// args.set("name", name.as_str());
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.insert("count", &3).unwrap();
+    /// ser.merge_into(&mut args);
+    ///
+    /// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+    /// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+    /// ```
+    pub fn merge_into<'a>(self, args: &mut FluentArgs<'a>) {
+        for (key, value) in self.args {
+            args.set(key, value);
+        }
+    }
+
+    /// Returns the built [`FluentArgs`] value, with the errors recorded while
+    /// [collecting errors](Self::set_collect_errors), so callers can decide whether
+    /// the arguments are still usable. Each error names its argument, if known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_serde::ser::ArgsSerializer;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Args {
+    ///     name: String,
+    ///     tags: Vec<String>,
+    /// }
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// ser.set_collect_errors(true);
+    /// Args { name: "Alice".into(), tags: vec![] }.serialize(&mut ser).unwrap();
+    ///
+    /// let (args, errors) = ser.done_partial();
+    /// assert!(args.get("name").is_some());
+    /// assert_eq!(vec![Some("tags")], errors.iter().map(|e| e.key()).collect::<Vec<_>>());
+    /// ```
+    pub fn done_partial(self) -> (FluentArgs<'static>, Vec<Error>) {
+        (self.args, self.errors.unwrap_or_default())
+    }
+
+    /// Takes the arguments serialized so far, leaving the serializer ready for the
+    /// next value with room for as many arguments. Options, lint, observers, and
+    /// metrics are kept.
+    ///
+    /// The taken [`FluentArgs`] owns its buffer, so the serializer allocates a new
+    /// one of the same size up front. This saves growing it again argument by
+    /// argument, but is not an allocation-free reuse.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::ArgsSerializer;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Args {
+    ///     name: String,
+    /// }
+    ///
+    /// let mut ser = ArgsSerializer::new();
+    /// for name in ["Alice", "Bob"] {
+    ///     Args { name: name.into() }.serialize(&mut ser).unwrap();
+    ///     let args = ser.take();
+    ///     assert_eq!(Some(&FluentValue::from(name)), args.get("name"));
+    /// }
+    /// ```
+    pub fn take(&mut self) -> FluentArgs<'static> {
+        self.capacity = self.args.iter().count().max(self.capacity);
+        std::mem::replace(&mut self.args, FluentArgs::with_capacity(self.capacity))
+    }
+
+    /// Discards the arguments serialized so far, leaving the serializer ready for
+    /// the next value with room for as many arguments. Options, lint, observers,
+    /// and metrics are kept.
+    ///
+    /// [`FluentArgs`] cannot be cleared in place, so its buffer is dropped and a new
+    /// one of the same size is allocated, as with [`take`](Self::take). Only the
+    /// capacity is carried over, not the allocation itself.
+    pub fn clear(&mut self) {
+        self.take();
+    }
 }
 
 impl From<FluentArgs<'static>> for ArgsSerializer {
@@ -82,7 +710,10 @@ impl From<FluentArgs<'static>> for ArgsSerializer {
     }
 }
 
-impl<'a> Serializer for &'a mut ArgsSerializer {
+impl<'a, S> Serializer for &'a mut ArgsSerializer<S>
+where
+    S: ArgSink,
+{
     type Ok = ();
     type Error = Error;
 
@@ -90,73 +721,115 @@ impl<'a> Serializer for &'a mut ArgsSerializer {
     type SerializeTuple = Unsupported<()>;
     type SerializeTupleStruct = Unsupported<()>;
     type SerializeTupleVariant = Unsupported<()>;
-    type SerializeMap = SerMap<'a>;
-    type SerializeStruct = SerStruct<'a>;
-    type SerializeStructVariant = SerStructVariant<'a>;
+    type SerializeMap = SerMap<'a, S>;
+    type SerializeStruct = SerStruct<'a, S>;
+    type SerializeStructVariant = SerStructVariant<'a, S>;
 
     fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Bool,
+            name: None,
+        })
     }
 
     fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Number,
+            name: None,
+        })
     }
 
     fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Char,
+            name: None,
+        })
     }
 
     fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Str,
+            name: None,
+        })
     }
 
     fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Bytes,
+            name: None,
+        })
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
         Ok(())
     }
 
-    fn serialize_some<T: ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self)
     }
@@ -178,18 +851,18 @@ impl<'a> Serializer for &'a mut ArgsSerializer {
         Ok(())
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(
+    fn serialize_newtype_struct<T>(
         self,
         _name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self)
     }
 
-    fn serialize_newtype_variant<T: ?Sized>(
+    fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
         _variant_index: u32,
@@ -197,52 +870,67 @@ impl<'a> Serializer for &'a mut ArgsSerializer {
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self)
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Seq,
+            name: None,
+        })
     }
 
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Tuple,
+            name: None,
+        })
     }
 
     fn serialize_tuple_struct(
         self,
-        _name: &'static str,
+        name: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::TupleStruct,
+            name: Some(name),
+        })
     }
 
     fn serialize_tuple_variant(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variant_index: u32,
         _variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::TupleVariant,
+            name: Some(name),
+        })
     }
 
-    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        if let Some(len) = len {
+            self.reserve(len);
+        }
         Ok(SerMap {
-            args: &mut self.args,
+            ser: self,
             current_key: None,
+            skip_value: false,
         })
     }
 
     fn serialize_struct(
         self,
         _name: &'static str,
-        _len: usize,
+        len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        Ok(SerStruct {
-            args: &mut self.args,
-        })
+        self.reserve(len);
+        Ok(SerStruct { ser: self })
     }
 
     fn serialize_struct_variant(
@@ -250,48 +938,222 @@ impl<'a> Serializer for &'a mut ArgsSerializer {
         _name: &'static str,
         _variant_index: u32,
         _variant: &'static str,
-        _len: usize,
+        len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        Ok(SerStructVariant {
-            args: &mut self.args,
-        })
+        self.reserve(len);
+        Ok(SerStructVariant { ser: self })
+    }
+}
+
+/// Arguments overwritten by [`ArgsSerializer::merge_reporting`].
+#[derive(Debug, Default)]
+pub struct MergeReport {
+    overwritten: Vec<(String, FluentValue<'static>)>,
+}
+
+impl MergeReport {
+    /// Returns the overwritten argument names with their previous values, in the
+    /// order they were overwritten.
+    pub fn overwritten(&self) -> &[(String, FluentValue<'static>)] {
+        &self.overwritten
+    }
+
+    /// Returns `true` if no arguments were overwritten.
+    pub fn is_empty(&self) -> bool {
+        self.overwritten.is_empty()
+    }
+
+    /// Returns the overwritten argument names with their previous values.
+    pub fn into_overwritten(self) -> Vec<(String, FluentValue<'static>)> {
+        self.overwritten
     }
 }
 
+/// Inserts pre-built arguments. Lint, observers, and metrics apply as for
+/// serialized values, but the values are not transformed by the options.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::ArgsSerializer;
+///
+/// let mut ser = ArgsSerializer::new();
+/// ser.extend(vec![(Cow::Borrowed("count"), FluentValue::from(3))]);
+/// ser.extend(vec![("name".to_string(), FluentValue::from("Alice"))]);
+/// let args = ser.done();
+///
+/// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// ```
+impl<S> Extend<(Cow<'static, str>, FluentValue<'static>)> for ArgsSerializer<S>
+where
+    S: ArgSink,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (Cow<'static, str>, FluentValue<'static>)>,
+    {
+        for (key, value) in iter {
+            self.insert_fluent_value(key, value);
+        }
+    }
+}
+
+impl<S> Extend<(String, FluentValue<'static>)> for ArgsSerializer<S>
+where
+    S: ArgSink,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (String, FluentValue<'static>)>,
+    {
+        self.extend(
+            iter.into_iter()
+                .map(|(key, value)| (Cow::Owned(key), value)),
+        );
+    }
+}
+
+/// Collects pre-built arguments into a new [`ArgsSerializer`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::ArgsSerializer;
+///
+/// let ser: ArgsSerializer = vec![("name".to_string(), "Alice".to_string())]
+///     .into_iter()
+///     .collect();
+///
+/// assert_eq!(Some(&FluentValue::from("Alice")), ser.done().get("name"));
+/// ```
+impl FromIterator<(Cow<'static, str>, FluentValue<'static>)> for ArgsSerializer {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Cow<'static, str>, FluentValue<'static>)>,
+    {
+        let mut ser = ArgsSerializer::new();
+        ser.extend(iter);
+        ser
+    }
+}
+
+impl FromIterator<(String, String)> for ArgsSerializer {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        iter.into_iter()
+            .map(|(key, value)| (Cow::Owned(key), FluentValue::from(value)))
+            .collect()
+    }
+}
+
+/// Serializes `value` into a [`FluentArgs`], with the default options.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     name: String,
+/// }
+///
+/// let args = fluent_serde::to_args(&Args { name: "Alice".into() }).unwrap();
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// ```
+pub fn to_args<T>(value: &T) -> Result<FluentArgs<'static>, Error>
+where
+    T: ?Sized + serde::Serialize,
+{
+    to_args_with(value, SerializerOptions::default())
+}
+
+/// Serializes `value` into a [`FluentArgs`], with `options`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::{to_args_with, ResultPolicy, SerializerOptions};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     name: String,
+///     avatar: Result<String, String>,
+/// }
+///
+/// let options = SerializerOptions::new().results(ResultPolicy::Skip);
+/// let value = Args { name: "Alice".into(), avatar: Err("not found".into()) };
+/// let args = to_args_with(&value, options).unwrap();
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// assert_eq!(None, args.get("avatar"));
+/// ```
+pub fn to_args_with<T>(value: &T, options: SerializerOptions) -> Result<FluentArgs<'static>, Error>
+where
+    T: ?Sized + serde::Serialize,
+{
+    let mut ser = ArgsSerializer::with_options(options);
+    value.serialize(&mut ser)?;
+    Ok(ser.done())
+}
+
 /// Map serialization interface.
-pub struct SerMap<'a> {
-    args: &'a mut FluentArgs<'static>,
+pub struct SerMap<'a, S = FluentArgs<'static>> {
+    ser: &'a mut ArgsSerializer<S>,
     current_key: Option<Cow<'static, str>>,
+    /// Whether the next value is skipped, after its key failed and was recorded.
+    skip_value: bool,
 }
 
-impl<'a> SerializeMap for SerMap<'a> {
+impl<'a, S> SerializeMap for SerMap<'a, S>
+where
+    S: ArgSink,
+{
     type Ok = ();
     type Error = Error;
 
-    fn serialize_key<T: ?Sized>(&mut self, key: &T) -> Result<(), Self::Error>
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        let value = key.serialize(ValueSerializer::new())?;
+        if self.current_key.is_some() || self.skip_value {
+            let err = Error::InvalidSerMap;
+            self.ser.notify_error(None, &err);
+            return Err(err);
+        }
 
-        if let FluentValue::String(key) = value {
-            if self.current_key.replace(key).is_some() {
-                Err(Error::InvalidSerMap)
-            } else {
-                Ok(())
+        let err = match key.serialize(
+            KeySerializer::new(self.ser.interner.clone()).strict(self.ser.options.is_strict()),
+        ) {
+            Ok(key) => {
+                self.current_key = Some(key);
+                return Ok(());
             }
-        } else {
-            Err(Error::UnsupportedType)
-        }
+            Err(err) => err,
+        };
+
+        self.ser.notify_error(None, &err);
+        self.skip_value = self.ser.errors.is_some();
+        self.ser.fail(err)
     }
 
-    fn serialize_value<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         if let Some(key) = self.current_key.take() {
-            let value = value.serialize(ValueSerializer::new())?;
-            self.args.set(key, value);
+            self.ser.insert_value(key, value)
+        } else if self.skip_value {
+            self.skip_value = false;
             Ok(())
         } else {
             Err(Error::InvalidSerMap)
@@ -299,7 +1161,7 @@ impl<'a> SerializeMap for SerMap<'a> {
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        if self.current_key.is_none() {
+        if self.current_key.is_none() && !self.skip_value {
             Ok(())
         } else {
             Err(Error::InvalidSerMap)
@@ -308,25 +1170,22 @@ impl<'a> SerializeMap for SerMap<'a> {
 }
 
 /// Struct serialization interface.
-pub struct SerStruct<'a> {
-    args: &'a mut FluentArgs<'static>,
+pub struct SerStruct<'a, S = FluentArgs<'static>> {
+    ser: &'a mut ArgsSerializer<S>,
 }
 
-impl<'a> SerializeStruct for SerStruct<'a> {
+impl<'a, S> SerializeStruct for SerStruct<'a, S>
+where
+    S: ArgSink,
+{
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        let value = value.serialize(ValueSerializer::new())?;
-        self.args.set(Cow::Borrowed(key), value);
-        Ok(())
+        self.ser.insert_value(Cow::Borrowed(key), value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
@@ -335,28 +1194,75 @@ impl<'a> SerializeStruct for SerStruct<'a> {
 }
 
 /// Struct variant serialization interface.
-pub struct SerStructVariant<'a> {
-    args: &'a mut FluentArgs<'static>,
+pub struct SerStructVariant<'a, S = FluentArgs<'static>> {
+    ser: &'a mut ArgsSerializer<S>,
 }
 
-impl<'a> SerializeStructVariant for SerStructVariant<'a> {
+impl<'a, S> SerializeStructVariant for SerStructVariant<'a, S>
+where
+    S: ArgSink,
+{
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        let value = value.serialize(ValueSerializer::new())?;
-        self.args.set(Cow::Borrowed(key), value);
-        Ok(())
+        self.ser.insert_value(Cow::Borrowed(key), value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
         Ok(())
     }
 }
+
+/// Conversion into a [`FluentArgs`], for APIs that accept both serializable
+/// values and pre-built arguments.
+///
+/// This is implemented for every [`Serialize`](serde::Serialize) type through
+/// [`to_args`]. A [`FluentArgs`] cannot implement it directly, because the blanket
+/// implementation would conflict with it; wrap pre-built arguments in
+/// [`OwnedArgs`](crate::wrappers::OwnedArgs) instead, which copies them with their
+/// formatting options and custom values.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentArgs, FluentValue};
+/// use fluent_serde::wrappers::OwnedArgs;
+/// use fluent_serde::ToFluentArgs;
+/// use serde::Serialize;
+///
+/// fn name(args: &impl ToFluentArgs) -> Option<String> {
+///     let args = args.to_fluent_args().ok()?;
+///     match args.get("name")? {
+///         FluentValue::String(s) => Some(s.to_string()),
+///         _ => None,
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     name: &'static str,
+/// }
+///
+/// let mut args = FluentArgs::new();
+/// args.set("name", "Bob");
+///
+/// assert_eq!(Some("Alice".into()), name(&Args { name: "Alice" }));
+/// assert_eq!(Some("Bob".into()), name(&OwnedArgs(args)));
+/// ```
+pub trait ToFluentArgs {
+    /// Converts `self` into a [`FluentArgs`].
+    fn to_fluent_args(&self) -> Result<FluentArgs<'static>, Error>;
+}
+
+impl<T> ToFluentArgs for T
+where
+    T: ?Sized + serde::Serialize,
+{
+    fn to_fluent_args(&self) -> Result<FluentArgs<'static>, Error> {
+        to_args(self)
+    }
+}
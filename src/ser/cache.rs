@@ -0,0 +1,515 @@
+//! Caching of serialized arguments.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use fluent::{FluentArgs, FluentValue};
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{Serialize, Serializer};
+
+use super::{to_args_with, Error, SerializerOptions};
+
+type Entry = Vec<(Cow<'static, str>, FluentValue<'static>)>;
+
+/// Caches [`FluentArgs`] by the content of the values they are serialized from, for
+/// values that recur often, such as rows of a list sharing the same arguments.
+///
+/// Values are looked up by an exact binary encoding of their serialized form, so
+/// values that serialize the same share an entry regardless of their type. Enum
+/// variants are encoded with the name of their enum, as serialization may depend
+/// on it. When the cache is full, the oldest entry is evicted to make room for the
+/// next one.
+///
+/// Cached arguments are cloned on each hit, without going through an
+/// [`ArgsSerializer`](super::ArgsSerializer), so lints, observers, and metrics do
+/// not apply.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::ArgsCache;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args<'a> {
+///     status: &'a str,
+/// }
+///
+/// let mut cache = ArgsCache::new(64);
+/// for status in ["open", "open", "closed", "open"] {
+///     let args = cache.get_or_serialize(&Args { status }).unwrap();
+///     assert_eq!(Some(&FluentValue::from(status)), args.get("status"));
+/// }
+///
+/// assert_eq!(2, cache.len());
+/// ```
+///
+/// A [`Result`] error and a variant of another enum with the same name do not
+/// share an entry:
+///
+/// ```rust
+/// use fluent_serde::ser::{ArgsCache, ResultPolicy, SerializerOptions};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// enum Outcome {
+///     Err(String),
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Args<T> {
+///     outcome: T,
+/// }
+///
+/// let options = SerializerOptions::new().results(ResultPolicy::Skip);
+/// let mut cache = ArgsCache::with_options(64, options);
+///
+/// let result: Result<u32, String> = Err("x".into());
+/// let args = cache.get_or_serialize(&Args { outcome: result }).unwrap();
+/// assert_eq!(None, args.get("outcome"));
+///
+/// let args = cache.get_or_serialize(&Args { outcome: Outcome::Err("x".into()) }).unwrap();
+/// assert!(args.get("outcome").is_some());
+/// assert_eq!(2, cache.len());
+/// ```
+pub struct ArgsCache {
+    entries: HashMap<Vec<u8>, Entry>,
+    /// Keys of `entries`, oldest first.
+    order: VecDeque<Vec<u8>>,
+    max_entries: usize,
+    options: SerializerOptions,
+    key: Vec<u8>,
+}
+
+impl fmt::Debug for ArgsCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArgsCache")
+            .field("len", &self.entries.len())
+            .field("max_entries", &self.max_entries)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArgsCache {
+    /// Creates an empty cache holding at most `max_entries` entries.
+    pub fn new(max_entries: usize) -> Self {
+        Self::with_options(max_entries, SerializerOptions::default())
+    }
+
+    /// Creates an empty cache holding at most `max_entries` entries, serializing
+    /// values with `options`.
+    pub fn with_options(max_entries: usize, options: SerializerOptions) -> Self {
+        ArgsCache {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            max_entries,
+            options,
+            key: Vec::new(),
+        }
+    }
+
+    /// Returns the options values are serialized with.
+    pub fn options(&self) -> &SerializerOptions {
+        &self.options
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Empties the cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// Returns the arguments serialized from `value`, from the cache if an equal
+    /// value was serialized before.
+    ///
+    /// On a miss, `value` is serialized twice: once to look it up, and once to
+    /// build the arguments.
+    pub fn get_or_serialize<T>(&mut self, value: &T) -> Result<FluentArgs<'static>, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.key.clear();
+        value.serialize(KeyEncoder { buf: &mut self.key })?;
+
+        if let Some(entry) = self.entries.get(&self.key) {
+            return Ok(entry.iter().cloned().collect());
+        }
+
+        let entry: Entry = to_args_with(value, self.options)?.into_iter().collect();
+        let args = entry.iter().cloned().collect();
+
+        if self.max_entries > 0 {
+            if self.entries.len() >= self.max_entries {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.order.push_back(self.key.clone());
+            self.entries.insert(self.key.clone(), entry);
+        }
+        Ok(args)
+    }
+}
+
+/// Encodes the serialized form of a value into bytes, such that different forms
+/// never share an encoding.
+struct KeyEncoder<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl KeyEncoder<'_> {
+    fn tag(&mut self, tag: u8) {
+        self.buf.push(tag);
+    }
+
+    fn len(&mut self, len: usize) {
+        self.buf.extend_from_slice(&(len as u64).to_le_bytes());
+    }
+
+    fn bytes(&mut self, v: &[u8]) {
+        self.len(v.len());
+        self.buf.extend_from_slice(v);
+    }
+
+    fn reborrow(&mut self) -> KeyEncoder<'_> {
+        KeyEncoder { buf: self.buf }
+    }
+}
+
+const FIELD: u8 = 0xfe;
+const END: u8 = 0xff;
+
+macro_rules! impl_encode_num {
+    ($($f:ident($t:ty) = $tag:expr,)*) => {
+        $(
+            fn $f(mut self, v: $t) -> Result<Self::Ok, Self::Error> {
+                self.tag($tag);
+                self.buf.extend_from_slice(&v.to_le_bytes());
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<'a> Serializer for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    impl_encode_num! {
+        serialize_i8(i8) = 1,
+        serialize_i16(i16) = 2,
+        serialize_i32(i32) = 3,
+        serialize_i64(i64) = 4,
+        serialize_i128(i128) = 5,
+        serialize_u8(u8) = 6,
+        serialize_u16(u16) = 7,
+        serialize_u32(u32) = 8,
+        serialize_u64(u64) = 9,
+        serialize_u128(u128) = 10,
+        serialize_f32(f32) = 11,
+        serialize_f64(f64) = 12,
+    }
+
+    fn serialize_bool(mut self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.tag(13);
+        self.buf.push(v as u8);
+        Ok(())
+    }
+
+    fn serialize_char(mut self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.tag(14);
+        self.buf.extend_from_slice(&(v as u32).to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_str(mut self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.tag(15);
+        self.bytes(v.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_bytes(mut self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.tag(16);
+        self.bytes(v);
+        Ok(())
+    }
+
+    fn serialize_none(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(17);
+        Ok(())
+    }
+
+    fn serialize_some<T>(mut self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.tag(18);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(19);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(mut self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.tag(20);
+        self.bytes(name.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        mut self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.tag(21);
+        self.bytes(name.as_bytes());
+        self.bytes(variant.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T>(
+        mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.tag(22);
+        self.bytes(name.as_bytes());
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        mut self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.tag(23);
+        self.bytes(name.as_bytes());
+        self.bytes(variant.as_bytes());
+        value.serialize(self)
+    }
+
+    fn serialize_seq(mut self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.tag(24);
+        Ok(self)
+    }
+
+    fn serialize_tuple(mut self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.tag(25);
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        mut self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.tag(26);
+        self.bytes(name.as_bytes());
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        mut self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.tag(27);
+        self.bytes(name.as_bytes());
+        self.bytes(variant.as_bytes());
+        Ok(self)
+    }
+
+    fn serialize_map(mut self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.tag(28);
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        mut self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.tag(29);
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        mut self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.tag(30);
+        self.bytes(name.as_bytes());
+        self.bytes(variant.as_bytes());
+        Ok(self)
+    }
+}
+
+impl<'a> SerializeSeq for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.reborrow())
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(END);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeTuple for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.reborrow())
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(END);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeTupleStruct for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.reborrow())
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(END);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeTupleVariant for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.reborrow())
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(END);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeMap for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        key.serialize(self.reborrow())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self.reborrow())
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(END);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeStruct for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.tag(FIELD);
+        self.bytes(key.as_bytes());
+        value.serialize(self.reborrow())
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(END);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeStructVariant for KeyEncoder<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.tag(FIELD);
+        self.bytes(key.as_bytes());
+        value.serialize(self.reborrow())
+    }
+
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        self.tag(END);
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! Interning of argument names.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+/// Interns map keys serialized by [`ArgsSerializer`](super::ArgsSerializer)s, so
+/// that maps with dynamic keys repeated across calls do not allocate a new name for
+/// each argument.
+///
+/// Interned keys are leaked, and live until the program exits. To bound the memory
+/// used, at most `max_keys` keys are interned. Keys beyond that limit are allocated
+/// as usual. Struct field names are static already, and never interned.
+///
+/// Clones share the same keys, so a single [`KeyInterner`] can be set on many
+/// serializers, across threads.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use fluent_serde::ser::{ArgsSerializer, KeyInterner};
+/// use serde::Serialize;
+///
+/// let interner = KeyInterner::new(1024);
+///
+/// for _ in 0..3 {
+///     let mut args = BTreeMap::new();
+///     args.insert(String::from("user"), "Alice");
+///
+///     let mut ser = ArgsSerializer::new();
+///     ser.set_key_interner(&interner);
+///     args.serialize(&mut ser).unwrap();
+/// }
+///
+/// assert_eq!(1, interner.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyInterner {
+    keys: Arc<RwLock<HashSet<&'static str>>>,
+    max_keys: usize,
+}
+
+impl KeyInterner {
+    /// Creates an empty interner, interning at most `max_keys` keys.
+    pub fn new(max_keys: usize) -> Self {
+        KeyInterner {
+            keys: Arc::default(),
+            max_keys,
+        }
+    }
+
+    /// Returns the number of interned keys.
+    pub fn len(&self) -> usize {
+        self.keys
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .len()
+    }
+
+    /// Returns `true` if no keys are interned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn intern(&self, key: &str) -> Cow<'static, str> {
+        if let Some(&key) = self
+            .keys
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(key)
+        {
+            return Cow::Borrowed(key);
+        }
+
+        let mut keys = self.keys.write().unwrap_or_else(|err| err.into_inner());
+        if let Some(&key) = keys.get(key) {
+            return Cow::Borrowed(key);
+        }
+        if keys.len() >= self.max_keys {
+            return Cow::Owned(key.to_string());
+        }
+        let key: &'static str = Box::leak(key.into());
+        keys.insert(key);
+        Cow::Borrowed(key)
+    }
+}
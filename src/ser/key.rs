@@ -0,0 +1,248 @@
+//! Serializer for argument names.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use serde::Serializer;
+
+use super::unsupported::Unsupported;
+use super::{DataKind, Error, KeyInterner};
+
+/// Serializes map keys into argument names.
+///
+/// Strings, characters, and unit variants are used as they are. Numbers and
+/// booleans are formatted, so that integer identifiers keep all their digits.
+/// Types serialized through `collect_str`, such as UUIDs, are formatted with
+/// [`Display`](fmt::Display).
+///
+/// Compound keys, such as tuples and structures, fail with [`Error::InvalidKey`],
+/// rendered with their contents elided.
+///
+/// Under [`SerializerOptions::strict`](super::SerializerOptions::strict), numbers,
+/// booleans, and characters fail with [`Error::Conversion`] instead.
+#[derive(Default)]
+pub(crate) struct KeySerializer {
+    interner: Option<KeyInterner>,
+    strict: bool,
+}
+
+impl KeySerializer {
+    pub(crate) fn new(interner: Option<KeyInterner>) -> Self {
+        KeySerializer {
+            interner,
+            strict: false,
+        }
+    }
+
+    pub(crate) fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Fails under strict mode, for keys of `kind` that are not strings.
+    fn coerce(&self, kind: DataKind) -> Result<(), Error> {
+        if self.strict {
+            return Err(Error::Conversion { kind });
+        }
+        Ok(())
+    }
+
+    fn key(&self, key: &str) -> Cow<'static, str> {
+        match &self.interner {
+            Some(interner) => interner.intern(key),
+            None => Cow::Owned(key.to_string()),
+        }
+    }
+
+    /// Fails for compound keys of `kind`, rendered as `key` with their contents
+    /// elided.
+    fn invalid(kind: DataKind, key: String) -> Error {
+        Error::InvalidKey { key, kind }
+    }
+}
+
+macro_rules! impl_format_key {
+    ($($f:ident($t:ty) => $kind:ident),* $(,)?) => {
+        $(
+            fn $f(self, v: $t) -> Result<Self::Ok, Self::Error> {
+                self.coerce(DataKind::$kind)?;
+                self.collect_str(&v)
+            }
+        )*
+    };
+}
+
+impl Serializer for KeySerializer {
+    type Ok = Cow<'static, str>;
+    type Error = Error;
+
+    type SerializeSeq = Unsupported<Self::Ok>;
+    type SerializeTuple = Unsupported<Self::Ok>;
+    type SerializeTupleStruct = Unsupported<Self::Ok>;
+    type SerializeTupleVariant = Unsupported<Self::Ok>;
+    type SerializeMap = Unsupported<Self::Ok>;
+    type SerializeStruct = Unsupported<Self::Ok>;
+    type SerializeStructVariant = Unsupported<Self::Ok>;
+
+    impl_format_key! {
+        serialize_bool(bool) => Bool,
+        serialize_i8(i8) => Number,
+        serialize_i16(i16) => Number,
+        serialize_i32(i32) => Number,
+        serialize_i64(i64) => Number,
+        serialize_i128(i128) => Number,
+        serialize_u8(u8) => Number,
+        serialize_u16(u16) => Number,
+        serialize_u32(u32) => Number,
+        serialize_u64(u64) => Number,
+        serialize_u128(u128) => Number,
+        serialize_f32(f32) => Number,
+        serialize_f64(f64) => Number,
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.coerce(DataKind::Char)?;
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(self.key(v))
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        let key = value.to_string();
+        Ok(match &self.interner {
+            Some(interner) => interner.intern(&key),
+            None => Cow::Owned(key),
+        })
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        let s = std::str::from_utf8(v).map_err(|err| Error::non_utf8_bytes(v, err))?;
+        self.serialize_str(s)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(Error::InvalidKey {
+            key: "none".to_string(),
+            kind: DataKind::None,
+        })
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(Error::InvalidKey {
+            key: "()".to_string(),
+            kind: DataKind::Unit,
+        })
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(Cow::Borrowed(name))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Cow::Borrowed(variant))
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        Err(Self::invalid(
+            DataKind::NewtypeVariant,
+            format!("{}::{}(..)", name, variant),
+        ))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(Self::invalid(DataKind::Seq, "[..]".to_string()))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(Self::invalid(DataKind::Tuple, "(..)".to_string()))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(Self::invalid(
+            DataKind::TupleStruct,
+            format!("{}(..)", name),
+        ))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(Self::invalid(
+            DataKind::TupleVariant,
+            format!("{}::{}(..)", name, variant),
+        ))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(Self::invalid(DataKind::Map, "{..}".to_string()))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(Self::invalid(
+            DataKind::Struct,
+            format!("{} {{ .. }}", name),
+        ))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(Self::invalid(
+            DataKind::StructVariant,
+            format!("{}::{} {{ .. }}", name, variant),
+        ))
+    }
+}
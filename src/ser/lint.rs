@@ -0,0 +1,106 @@
+//! Linting of string values.
+
+use std::fmt;
+
+/// Problem found in a string value by a [`StringLint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LintIssue {
+    /// The value contains a line break.
+    Newline,
+    /// The value contains a control character other than a line break.
+    ControlCharacter,
+    /// The value is longer than the maximum length, in characters.
+    TooLong { len: usize, max_len: usize },
+}
+
+type LintFn = dyn Fn(&str, &str, LintIssue) + Send + Sync;
+
+/// Checks string values inserted by an [`ArgsSerializer`](super::ArgsSerializer),
+/// calling a hook with the argument name, the value, and the issue for each problem
+/// found.
+///
+/// Values are checked after all [`SerializerOptions`](super::SerializerOptions) are
+/// applied.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+///
+/// use fluent_serde::ser::{ArgsSerializer, LintIssue, StringLint};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     title: String,
+/// }
+///
+/// let issues = Arc::new(Mutex::new(Vec::new()));
+/// let sink = Arc::clone(&issues);
+///
+/// let mut ser = ArgsSerializer::new();
+/// ser.set_lint(StringLint::new(move |key, _, issue| {
+///     sink.lock().unwrap().push((key.to_string(), issue));
+/// }).max_len(8));
+///
+/// Args { title: "A very\nlong title".into() }.serialize(&mut ser).unwrap();
+///
+/// assert_eq!(
+///     vec![
+///         ("title".to_string(), LintIssue::Newline),
+///         ("title".to_string(), LintIssue::TooLong { len: 17, max_len: 8 }),
+///     ],
+///     *issues.lock().unwrap(),
+/// );
+/// ```
+pub struct StringLint {
+    max_len: Option<usize>,
+    hook: Box<LintFn>,
+}
+
+impl fmt::Debug for StringLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StringLint")
+            .field("max_len", &self.max_len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StringLint {
+    /// Creates a [`StringLint`] checking for line breaks and control characters,
+    /// calling `hook` for each issue found.
+    pub fn new<F>(hook: F) -> Self
+    where
+        F: Fn(&str, &str, LintIssue) + Send + Sync + 'static,
+    {
+        StringLint {
+            max_len: None,
+            hook: Box::new(hook),
+        }
+    }
+
+    /// Sets the maximum length of values, in characters.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    pub(crate) fn check(&self, key: &str, value: &str) {
+        if value.contains(['\n', '\r']) {
+            (self.hook)(key, value, LintIssue::Newline);
+        }
+        if value
+            .chars()
+            .any(|c| c.is_control() && c != '\n' && c != '\r')
+        {
+            (self.hook)(key, value, LintIssue::ControlCharacter);
+        }
+        if let Some(max_len) = self.max_len {
+            let len = value.chars().count();
+            if len > max_len {
+                (self.hook)(key, value, LintIssue::TooLong { len, max_len });
+            }
+        }
+    }
+}
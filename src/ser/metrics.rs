@@ -0,0 +1,98 @@
+//! Serialization metrics.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+#[derive(Debug, Default)]
+struct Counters {
+    args: AtomicU64,
+    string_bytes: AtomicU64,
+    coercions: AtomicU64,
+    failures: AtomicU64,
+}
+
+/// Counters of the work done by [`ArgsSerializer`](super::ArgsSerializer)s, for
+/// monitoring the cost of building arguments.
+///
+/// Clones share the same counters, so a single [`ArgsMetrics`] can be set on many
+/// serializers, across threads, and read elsewhere.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_serde::ser::{ArgsMetrics, ArgsSerializer};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     name: String,
+///     admin: bool,
+/// }
+///
+/// let metrics = ArgsMetrics::new();
+///
+/// let mut ser = ArgsSerializer::new();
+/// ser.set_metrics(&metrics);
+/// Args { name: "Alice".into(), admin: true }.serialize(&mut ser).unwrap();
+///
+/// assert_eq!(2, metrics.args());
+/// assert_eq!(5, metrics.string_bytes());
+/// assert_eq!(1, metrics.coercions());
+/// assert_eq!(0, metrics.failures());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ArgsMetrics {
+    counters: Arc<Counters>,
+}
+
+impl ArgsMetrics {
+    /// Creates a new set of counters, all zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of arguments inserted.
+    pub fn args(&self) -> u64 {
+        self.counters.args.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total length of inserted string values, in bytes.
+    pub fn string_bytes(&self) -> u64 {
+        self.counters.string_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of values converted to a different kind, such as booleans
+    /// to numbers or unit variants to strings, including numbers that could not be
+    /// represented exactly.
+    pub fn coercions(&self) -> u64 {
+        self.counters.coercions.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of values or map keys that failed to serialize.
+    pub fn failures(&self) -> u64 {
+        self.counters.failures.load(Ordering::Relaxed)
+    }
+
+    /// Resets all counters to zero.
+    pub fn reset(&self) {
+        self.counters.args.store(0, Ordering::Relaxed);
+        self.counters.string_bytes.store(0, Ordering::Relaxed);
+        self.counters.coercions.store(0, Ordering::Relaxed);
+        self.counters.failures.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_arg(&self, string_bytes: usize) {
+        self.counters.args.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .string_bytes
+            .fetch_add(string_bytes as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_coercion(&self) {
+        self.counters.coercions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_failure(&self) {
+        self.counters.failures.fetch_add(1, Ordering::Relaxed);
+    }
+}
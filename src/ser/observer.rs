@@ -0,0 +1,60 @@
+//! Observation of [`ArgsSerializer`](super::ArgsSerializer) insertions.
+
+use fluent::FluentValue;
+
+use super::Error;
+
+/// Callbacks invoked by an [`ArgsSerializer`](super::ArgsSerializer) as it inserts
+/// arguments, for layering auditing, redaction, or metrics over serialization.
+///
+/// All methods do nothing by default.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::{ArgsObserver, ArgsSerializer};
+/// use serde::Serialize;
+///
+/// struct Redact;
+///
+/// impl ArgsObserver for Redact {
+///     fn on_insert(&mut self, key: &str, value: &mut FluentValue<'static>) {
+///         if key == "password" {
+///             *value = FluentValue::from("***");
+///         }
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Login {
+///     user: String,
+///     password: String,
+/// }
+///
+/// let mut ser = ArgsSerializer::new();
+/// ser.add_observer(Redact);
+/// Login { user: "alice".into(), password: "hunter2".into() }
+///     .serialize(&mut ser)
+///     .unwrap();
+///
+/// assert_eq!(Some(&FluentValue::from("***")), ser.done().get("password"));
+/// ```
+pub trait ArgsObserver: Send {
+    /// Called before argument `key` is inserted. The value can be modified.
+    fn on_insert(&mut self, key: &str, value: &mut FluentValue<'static>) {
+        let _ = (key, value);
+    }
+
+    /// Called before argument `key` is overwritten, after
+    /// [`on_insert`](ArgsObserver::on_insert).
+    fn on_overwrite(&mut self, key: &str, old: &FluentValue, new: &FluentValue) {
+        let _ = (key, old, new);
+    }
+
+    /// Called when serializing a value or map key fails. `key` is the argument being
+    /// serialized, if known.
+    fn on_error(&mut self, key: Option<&str>, error: &Error) {
+        let _ = (key, error);
+    }
+}
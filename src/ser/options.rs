@@ -0,0 +1,541 @@
+//! Serializer options.
+
+use std::borrow::Cow;
+
+use fluent::types::{FluentNumber, FluentNumberOptions};
+use fluent::FluentValue;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Name of the newtype struct [`Raw`] serializes as.
+pub(crate) const RAW_NAME: &str = "$fluent_serde::private::Raw";
+
+/// Name of the enum [`Static`] serializes a variant of.
+pub(crate) const STATIC_NAME: &str = "$fluent_serde::private::Static";
+
+/// Name `serde` serializes [`Result`] as.
+pub(crate) const RESULT_NAME: &str = "Result";
+
+/// Options for [`ValueSerializer`](super::ValueSerializer) and
+/// [`ArgsSerializer`](super::ArgsSerializer).
+///
+/// Options apply to argument values only. Argument names are never transformed.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::{PseudoOptions, SerializerOptions, ValueSerializer};
+/// use serde::Serialize;
+///
+/// let options = SerializerOptions::new().pseudo(PseudoOptions::new());
+/// let value = "Hello".serialize(ValueSerializer::with_options(options)).unwrap();
+/// assert_eq!(FluentValue::from("[Ħḗŀŀǿ~~]"), value);
+/// ```
+///
+/// Options can also be loaded from configuration files. Missing fields take their
+/// default values, and names are in snake case, as in the builder methods:
+///
+/// ```rust
+/// use fluent_serde::ser::SerializerOptions;
+/// use serde::de::value::{Error, MapDeserializer};
+/// use serde::Deserialize;
+///
+/// let config = vec![("html_escape", true), ("strict", true)];
+/// let deserializer = MapDeserializer::<_, Error>::new(config.into_iter());
+/// let options = SerializerOptions::deserialize(deserializer).unwrap();
+///
+/// assert_eq!(SerializerOptions::new().html_escape(true).strict(true), options);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct SerializerOptions {
+    pseudo: Option<PseudoOptions>,
+    html_escape: bool,
+    bidi_isolate: bool,
+    strip_ansi: bool,
+    strict_numbers: bool,
+    errors_as_values: bool,
+    strict: bool,
+    results: ResultPolicy,
+}
+
+impl SerializerOptions {
+    /// Creates the default options, under which values are not transformed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables pseudo-localization of values, for verifying that displayed text
+    /// flows through the serializer.
+    pub fn pseudo(mut self, pseudo: PseudoOptions) -> Self {
+        self.pseudo = Some(pseudo);
+        self
+    }
+
+    /// Returns the pseudo-localization options, if enabled.
+    pub fn pseudo_options(&self) -> Option<&PseudoOptions> {
+        self.pseudo.as_ref()
+    }
+
+    /// Sets whether string values are HTML-escaped, for messages interpolated into
+    /// HTML. Values wrapped in [`Raw`] are not escaped.
+    pub fn html_escape(mut self, html_escape: bool) -> Self {
+        self.html_escape = html_escape;
+        self
+    }
+
+    /// Returns `true` if string values are HTML-escaped.
+    pub fn is_html_escape(&self) -> bool {
+        self.html_escape
+    }
+
+    /// Sets whether string values are wrapped in Unicode isolation characters, FSI
+    /// (U+2068) and PDI (U+2069), so right-to-left values do not scramble
+    /// left-to-right messages and vice versa.
+    ///
+    /// Bundles already isolate placeables unless
+    /// [`set_use_isolating(false)`](fluent::bundle::FluentBundle::set_use_isolating)
+    /// is called. This option is meant for arguments formatted with isolation
+    /// disabled, or used outside of bundles.
+    pub fn bidi_isolate(mut self, bidi_isolate: bool) -> Self {
+        self.bidi_isolate = bidi_isolate;
+        self
+    }
+
+    /// Returns `true` if string values are wrapped in isolation characters.
+    pub fn is_bidi_isolate(&self) -> bool {
+        self.bidi_isolate
+    }
+
+    /// Sets whether ANSI escape sequences, such as terminal color codes, are
+    /// stripped from string values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::{SerializerOptions, ValueSerializer};
+    /// use serde::Serialize;
+    ///
+    /// let options = SerializerOptions::new().strip_ansi(true);
+    /// let value = "\x1b[31merror\x1b[0m".serialize(ValueSerializer::with_options(options));
+    /// assert_eq!(FluentValue::from("error"), value.unwrap());
+    /// ```
+    pub fn strip_ansi(mut self, strip_ansi: bool) -> Self {
+        self.strip_ansi = strip_ansi;
+        self
+    }
+
+    /// Returns `true` if ANSI escape sequences are stripped from string values.
+    pub fn is_strip_ansi(&self) -> bool {
+        self.strip_ansi
+    }
+
+    /// Sets whether numbers that cannot be represented exactly as [`f64`], such as
+    /// large [`u64`] identifiers, fail to serialize with
+    /// [`Error::PrecisionLoss`](super::Error::PrecisionLoss) instead of being
+    /// rounded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_serde::ser::{Error, SerializerOptions, ValueSerializer};
+    /// use serde::Serialize;
+    ///
+    /// let options = SerializerOptions::new().strict_numbers(true);
+    /// let err = u64::MAX.serialize(ValueSerializer::with_options(options));
+    /// assert!(matches!(err, Err(Error::PrecisionLoss { .. })));
+    /// ```
+    pub fn strict_numbers(mut self, strict_numbers: bool) -> Self {
+        self.strict_numbers = strict_numbers;
+        self
+    }
+
+    /// Returns `true` if numbers that cannot be represented exactly fail to
+    /// serialize.
+    pub fn is_strict_numbers(&self) -> bool {
+        self.strict_numbers
+    }
+
+    /// Sets whether every implicit conversion fails to serialize with
+    /// [`Error::Conversion`](super::Error::Conversion), so that serialized values
+    /// map back to their sources exactly. This covers booleans to numbers,
+    /// characters, byte strings, unit structs and unit variants to strings, and
+    /// implies [`strict_numbers`](Self::strict_numbers). Map keys that are numbers,
+    /// booleans, or characters fail too, rather than being formatted as names.
+    ///
+    /// Values wrapped in [`Static`] are strings already, and are not affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_serde::ser::{Error, SerializerOptions, ValueSerializer};
+    /// use serde::Serialize;
+    ///
+    /// let options = SerializerOptions::new().strict(true);
+    /// let err = true.serialize(ValueSerializer::with_options(options));
+    /// assert!(matches!(err, Err(Error::Conversion { .. })));
+    /// ```
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns `true` if implicit conversions fail to serialize.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether arguments whose values fail to serialize are inserted as
+    /// [`FluentValue::Error`] by [`ArgsSerializer`](super::ArgsSerializer), instead
+    /// of failing. Bundles format such arguments as empty strings, so the rest of the
+    /// message still renders.
+    ///
+    /// Failures are still reported to observers and metrics, and recorded if the
+    /// serializer collects errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::{ArgsSerializer, SerializerOptions};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Args {
+    ///     tags: Vec<String>,
+    /// }
+    ///
+    /// let options = SerializerOptions::new().errors_as_values(true);
+    /// let mut ser = ArgsSerializer::with_options(options);
+    /// Args { tags: vec![] }.serialize(&mut ser).unwrap();
+    /// assert!(matches!(ser.done().get("tags"), Some(FluentValue::Error)));
+    /// ```
+    pub fn errors_as_values(mut self, errors_as_values: bool) -> Self {
+        self.errors_as_values = errors_as_values;
+        self
+    }
+
+    /// Returns `true` if arguments that fail to serialize are inserted as errors.
+    pub fn is_errors_as_values(&self) -> bool {
+        self.errors_as_values
+    }
+
+    /// Sets how `Err` values of [`Result`] arguments are serialized. `Ok` values
+    /// are always serialized as the value they hold.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent::FluentValue;
+    /// use fluent_serde::ser::{ArgsSerializer, ResultPolicy, SerializerOptions};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Args {
+    ///     balance: Result<u32, String>,
+    /// }
+    ///
+    /// let options = SerializerOptions::new().results(ResultPolicy::ErrorKey);
+    /// let mut ser = ArgsSerializer::with_options(options);
+    /// ser.merge_from(&Args { balance: Err("offline".into()) }).unwrap();
+    /// let args = ser.done();
+    ///
+    /// assert!(matches!(args.get("balance"), Some(FluentValue::None)));
+    /// assert_eq!(Some(&FluentValue::from("offline")), args.get("balance-error"));
+    /// ```
+    pub fn results(mut self, results: ResultPolicy) -> Self {
+        self.results = results;
+        self
+    }
+
+    /// Returns how `Err` values of [`Result`] arguments are serialized.
+    pub fn result_policy(&self) -> ResultPolicy {
+        self.results
+    }
+
+    /// Returns the options applied to values wrapped in [`Raw`].
+    pub(crate) fn raw(self) -> Self {
+        SerializerOptions {
+            html_escape: false,
+            ..self
+        }
+    }
+
+    pub(crate) fn string_value(&self, mut s: Cow<'static, str>) -> FluentValue<'static> {
+        if self.strip_ansi && s.contains(['\x1b', '\u{9b}']) {
+            s = Cow::Owned(strip_ansi(&s));
+        }
+        if let Some(pseudo) = &self.pseudo {
+            s = Cow::Owned(pseudo.apply(&s));
+        }
+        if self.html_escape && s.contains(['&', '<', '>', '"', '\'']) {
+            s = Cow::Owned(escape_html(&s));
+        }
+        if self.bidi_isolate {
+            s = Cow::Owned(format!("\u{2068}{}\u{2069}", s));
+        }
+        FluentValue::String(s)
+    }
+
+    pub(crate) fn number_value(&self, n: f64) -> FluentValue<'static> {
+        match &self.pseudo {
+            Some(pseudo) if pseudo.numbers => {
+                FluentValue::String(Cow::Owned(pseudo.apply(&n.to_string())))
+            }
+            _ => FluentValue::Number(FluentNumber::new(n, FluentNumberOptions::default())),
+        }
+    }
+
+    /// Returns an empty value of the kind [`number_value`](Self::number_value)
+    /// returns, without formatting a number.
+    pub(crate) fn number_placeholder(&self) -> FluentValue<'static> {
+        match &self.pseudo {
+            Some(pseudo) if pseudo.numbers => FluentValue::String(Cow::Borrowed("")),
+            _ => FluentValue::Number(FluentNumber::new(0.0, FluentNumberOptions::default())),
+        }
+    }
+}
+
+/// Exempts a value from HTML escaping.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::{ArgsSerializer, Raw, SerializerOptions};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Args<'a> {
+///     name: &'a str,
+///     link: Raw<&'a str>,
+/// }
+///
+/// let mut ser = ArgsSerializer::with_options(SerializerOptions::new().html_escape(true));
+/// Args { name: "<b>Alice</b>", link: Raw("<a href=\"/\">Home</a>") }
+///     .serialize(&mut ser)
+///     .unwrap();
+/// let args = ser.done();
+///
+/// assert_eq!(Some(&FluentValue::from("&lt;b&gt;Alice&lt;/b&gt;")), args.get("name"));
+/// assert_eq!(Some(&FluentValue::from("<a href=\"/\">Home</a>")), args.get("link"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Raw<T>(pub T);
+
+impl<T> Serialize for Raw<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(RAW_NAME, &self.0)
+    }
+}
+
+/// Stores a static string value without copying it.
+///
+/// Strings are otherwise copied, as serializers cannot tell static strings apart.
+/// Options still apply, and may copy the value if they transform it. Other
+/// serializers see the value as a unit variant named after the string.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use fluent::FluentValue;
+/// use fluent_serde::ser::{Static, ValueSerializer};
+/// use serde::Serialize;
+///
+/// let value = Static("Alice").serialize(ValueSerializer::new()).unwrap();
+/// assert_eq!(FluentValue::String(Cow::Borrowed("Alice")), value);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Static(pub &'static str);
+
+impl Serialize for Static {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit_variant(STATIC_NAME, 0, self.0)
+    }
+}
+
+/// Removes CSI, OSC, and two-character escape sequences from `s`.
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{9b}' => {}
+            '\x1b' => match chars.next() {
+                Some('[') => {}
+                Some(']') => {
+                    // Operating system command, terminated by BEL or ST (ESC \).
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == '\x1b' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                    continue;
+                }
+                _ => continue,
+            },
+            c => {
+                out.push(c);
+                continue;
+            }
+        }
+
+        // Control sequence: parameter and intermediate bytes, up to a final byte
+        // in `@..=~`.
+        for c in chars.by_ref() {
+            if ('@'..='~').contains(&c) {
+                break;
+            }
+        }
+    }
+
+    out
+}
+
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 16);
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#x27;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// How `Err` values of [`Result`] arguments are serialized, set with
+/// [`SerializerOptions::results`].
+///
+/// Except for [`Inner`](Self::Inner), the error is rendered as a string, so it must
+/// be a string or a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ResultPolicy {
+    /// The error is serialized as the value of the argument, like an `Ok` value.
+    #[default]
+    Inner,
+    /// The argument is left out by [`ArgsSerializer`](super::ArgsSerializer).
+    Skip,
+    /// The error is serialized as a string.
+    ErrorString,
+    /// The argument is set to [`FluentValue::None`] by
+    /// [`ArgsSerializer`](super::ArgsSerializer), and the error is set as a string
+    /// under a companion argument, named after the argument with `-error` appended.
+    ErrorKey,
+}
+
+/// Pseudo-localization options.
+///
+/// By default, ASCII letters are replaced with accented forms, strings are padded by
+/// about 30% to simulate longer translations, and wrapped in brackets to make
+/// truncation visible. Numbers are left alone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PseudoOptions {
+    accents: bool,
+    padding: bool,
+    brackets: bool,
+    numbers: bool,
+}
+
+impl Default for PseudoOptions {
+    fn default() -> Self {
+        PseudoOptions {
+            accents: true,
+            padding: true,
+            brackets: true,
+            numbers: false,
+        }
+    }
+}
+
+impl PseudoOptions {
+    /// Creates the default pseudo-localization options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether ASCII letters are replaced with accented forms.
+    pub fn accents(mut self, accents: bool) -> Self {
+        self.accents = accents;
+        self
+    }
+
+    /// Sets whether strings are padded to simulate longer translations.
+    pub fn padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets whether strings are wrapped in brackets.
+    pub fn brackets(mut self, brackets: bool) -> Self {
+        self.brackets = brackets;
+        self
+    }
+
+    /// Sets whether numbers are pseudo-localized as well. Pseudo-localized numbers
+    /// become strings, so they no longer select plural variants.
+    pub fn numbers(mut self, numbers: bool) -> Self {
+        self.numbers = numbers;
+        self
+    }
+
+    fn apply(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len() * 2 + 2);
+        if self.brackets {
+            out.push('[');
+        }
+        if self.accents {
+            out.extend(s.chars().map(accent));
+        } else {
+            out.push_str(s);
+        }
+        if self.padding {
+            let len = s.chars().count();
+            out.extend(std::iter::repeat_n('~', (len * 3).div_ceil(10)));
+        }
+        if self.brackets {
+            out.push(']');
+        }
+        out
+    }
+}
+
+fn accent(c: char) -> char {
+    const UPPER: [char; 26] = [
+        'Ȧ', 'Ɓ', 'Ƈ', 'Ḓ', 'Ḗ', 'Ƒ', 'Ɠ', 'Ħ', 'Ī', 'Ĵ', 'Ķ', 'Ŀ', 'Ḿ', 'Ƞ', 'Ǿ', 'Ƥ', 'Ɋ', 'Ř',
+        'Ş', 'Ŧ', 'Ŭ', 'Ṽ', 'Ẇ', 'Ẋ', 'Ẏ', 'Ẑ',
+    ];
+    const LOWER: [char; 26] = [
+        'ȧ', 'ƀ', 'ƈ', 'ḓ', 'ḗ', 'ƒ', 'ɠ', 'ħ', 'ī', 'ĵ', 'ķ', 'ŀ', 'ḿ', 'ƞ', 'ǿ', 'ƥ', 'ɋ', 'ř',
+        'ş', 'ŧ', 'ŭ', 'ṽ', 'ẇ', 'ẋ', 'ẏ', 'ẑ',
+    ];
+
+    match c {
+        'A'..='Z' => UPPER[(c as u8 - b'A') as usize],
+        'a'..='z' => LOWER[(c as u8 - b'a') as usize],
+        _ => c,
+    }
+}
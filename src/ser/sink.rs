@@ -0,0 +1,49 @@
+//! Argument containers serialized into by [`ArgsSerializer`](super::ArgsSerializer).
+
+use std::borrow::Cow;
+
+use fluent::{FluentArgs, FluentValue};
+
+/// An argument container an [`ArgsSerializer`](super::ArgsSerializer) can serialize
+/// into, so that arguments need not be copied out of a [`FluentArgs`] afterwards.
+///
+/// See [`ArgsSerializer::with_sink`](super::ArgsSerializer::with_sink).
+pub trait ArgSink {
+    /// Sets the argument named `key` to `value`, replacing any previous value.
+    fn set(&mut self, key: Cow<'static, str>, value: FluentValue<'static>);
+
+    /// Returns the value of the argument named `key`, if set.
+    ///
+    /// This is used to report overwritten arguments to
+    /// [`ArgsObserver`](super::ArgsObserver)s and
+    /// [`merge_reporting`](super::ArgsSerializer::merge_reporting). The default
+    /// implementation returns `None`, so overwrites are not reported.
+    fn get<'s>(&'s self, key: &'s str) -> Option<&'s FluentValue<'s>> {
+        let _ = key;
+        None
+    }
+
+    /// Makes room for `len` arguments, before a structure or map of known length is
+    /// serialized. The default implementation does nothing.
+    fn reserve(&mut self, len: usize) {
+        let _ = len;
+    }
+}
+
+impl<'a> ArgSink for FluentArgs<'a> {
+    fn set(&mut self, key: Cow<'static, str>, value: FluentValue<'static>) {
+        FluentArgs::set(self, key, value);
+    }
+
+    fn get<'s>(&'s self, key: &'s str) -> Option<&'s FluentValue<'s>> {
+        FluentArgs::get(self, key)
+    }
+
+    /// Reallocates the arguments with room for `len` entries if they are empty.
+    /// `FluentArgs` cannot grow in place, so non-empty arguments are left alone.
+    fn reserve(&mut self, len: usize) {
+        if self.iter().next().is_none() {
+            *self = FluentArgs::with_capacity(len);
+        }
+    }
+}
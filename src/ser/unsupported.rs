@@ -7,33 +7,41 @@ use serde::ser::{
 
 use super::Error;
 
+/// Compound serializer for unsupported types, which serializers return errors for
+/// instead. It cannot be constructed, so its methods can never be called.
 pub struct Unsupported<Ok> {
-    _never: Never,
+    never: Never,
     _marker: PhantomData<fn() -> Ok>,
 }
 
 enum Never {}
 
+impl<Ok> Unsupported<Ok> {
+    fn unreachable(&self) -> ! {
+        match self.never {}
+    }
+}
+
 impl<Ok> SerializeMap for Unsupported<Ok> {
     type Ok = Ok;
     type Error = Error;
 
-    fn serialize_key<T: ?Sized>(&mut self, _key: &T) -> Result<(), Self::Error>
+    fn serialize_key<T>(&mut self, _key: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
-    fn serialize_value<T: ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error>
+    fn serialize_value<T>(&mut self, _value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        unreachable!()
+        self.unreachable()
     }
 }
 
@@ -41,15 +49,15 @@ impl<Ok> SerializeSeq for Unsupported<Ok> {
     type Ok = Ok;
     type Error = Error;
 
-    fn serialize_element<T: ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, _value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        unreachable!()
+        self.unreachable()
     }
 }
 
@@ -57,15 +65,15 @@ impl<Ok> SerializeTuple for Unsupported<Ok> {
     type Ok = Ok;
     type Error = Error;
 
-    fn serialize_element<T: ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, _value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        unreachable!()
+        self.unreachable()
     }
 }
 
@@ -73,15 +81,15 @@ impl<Ok> SerializeTupleStruct for Unsupported<Ok> {
     type Ok = Ok;
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        unreachable!()
+        self.unreachable()
     }
 }
 
@@ -89,52 +97,44 @@ impl<Ok> SerializeTupleVariant for Unsupported<Ok> {
     type Ok = Ok;
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        unreachable!()
+        self.unreachable()
     }
 }
 
 impl<Ok> SerializeStruct for Unsupported<Ok> {
     type Ok = Ok;
     type Error = Error;
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        _key: &'static str,
-        _value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _key: &'static str, _value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        unreachable!()
+        self.unreachable()
     }
 }
 
 impl<Ok> SerializeStructVariant for Unsupported<Ok> {
     type Ok = Ok;
     type Error = Error;
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        _key: &'static str,
-        _value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _key: &'static str, _value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
-        unreachable!()
+        self.unreachable()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        unreachable!()
+        self.unreachable()
     }
 }
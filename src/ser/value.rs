@@ -1,13 +1,15 @@
 //! Serializer for [`FluentValue`].
 
 use std::borrow::Cow;
+use std::fmt;
 
-use fluent::types::{FluentNumber, FluentNumberOptions};
 use fluent::FluentValue;
 use serde::Serializer;
 
+use super::options::{RAW_NAME, RESULT_NAME, STATIC_NAME};
 use super::unsupported::Unsupported;
-use super::Error;
+use super::{ArgsMetrics, DataKind, Error, ResultPolicy, SerializerOptions};
+use crate::render::render_value;
 
 /// Serialize into a [`FluentValue`]. The result is returned as [`Serializer::Ok`].
 ///
@@ -19,6 +21,10 @@ use super::Error;
 /// - Numbers, with potentially lossy conversion to [`f64`].
 /// - Unit structs and variants, encoded as strings.
 /// - [`Option`]s and newtypes of other supported types.
+/// - [`LanguageIdentifier`](unic_langid::LanguageIdentifier)s, as BCP 47 tags, with
+///   the `langid` feature.
+///
+/// Values may be transformed according to [`SerializerOptions`].
 ///
 /// See also [`ArgsSerializer`](crate::ser::ArgsSerializer).
 ///
@@ -37,7 +43,11 @@ use super::Error;
 /// ```
 #[derive(Default)]
 pub struct ValueSerializer {
-    _private: (),
+    options: SerializerOptions,
+    metrics: Option<ArgsMetrics>,
+    /// Whether empty values of the right kind are returned instead of the actual
+    /// values, to classify values without copying strings.
+    kind_only: bool,
 }
 
 impl ValueSerializer {
@@ -45,21 +55,160 @@ impl ValueSerializer {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Creates a new [`ValueSerializer`] with `options`.
+    pub fn with_options(options: SerializerOptions) -> Self {
+        ValueSerializer {
+            options,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn with_metrics(mut self, metrics: Option<ArgsMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub(crate) fn kind_only(mut self, kind_only: bool) -> Self {
+        self.kind_only = kind_only;
+        self
+    }
+
+    /// Converts the string made by `s`, or returns an empty string if only the kind
+    /// is needed.
+    pub(crate) fn string_value<F>(&self, s: F) -> FluentValue<'static>
+    where
+        F: FnOnce() -> Cow<'static, str>,
+    {
+        if self.kind_only {
+            FluentValue::String(Cow::Borrowed(""))
+        } else {
+            self.options.string_value(s())
+        }
+    }
+
+    fn number_value(&self, n: f64) -> FluentValue<'static> {
+        if self.kind_only {
+            self.options.number_placeholder()
+        } else {
+            self.options.number_value(n)
+        }
+    }
+
+    /// Records the conversion of a value of `kind` to a different kind, or fails
+    /// under [`SerializerOptions::strict`].
+    fn coerce(&self, kind: DataKind) -> Result<(), Error> {
+        if self.options.is_strict() {
+            return Err(Error::Conversion { kind });
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics.record_coercion();
+        }
+        Ok(())
+    }
+
+    /// Returns the options of the serializer.
+    pub fn options(&self) -> &SerializerOptions {
+        &self.options
+    }
+}
+
+/// Serializes `value` into a [`FluentValue`], with the default options.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+///
+/// let value = fluent_serde::to_value(&"Alice").unwrap();
+/// assert_eq!(FluentValue::from("Alice"), value);
+/// ```
+pub fn to_value<T>(value: &T) -> Result<FluentValue<'static>, Error>
+where
+    T: ?Sized + serde::Serialize,
+{
+    value.serialize(ValueSerializer::new())
+}
+
+/// Conversion into a [`FluentValue`], for APIs that accept both serializable
+/// values and pre-built Fluent values.
+///
+/// This is implemented for every [`Serialize`](serde::Serialize) type through
+/// [`to_value`]. Pre-built values are wrapped in
+/// [`OwnedValue`](crate::wrappers::OwnedValue), which is passed through as it is,
+/// since a direct implementation for [`FluentValue`] would conflict with the
+/// blanket one.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentArgs, FluentValue};
+/// use fluent_serde::wrappers::OwnedValue;
+/// use fluent_serde::IntoFluentValue;
+///
+/// fn insert(args: &mut FluentArgs<'static>, key: &'static str, value: impl IntoFluentValue) {
+///     args.set(key, value.into_fluent_value().unwrap());
+/// }
+///
+/// let mut args = FluentArgs::new();
+/// insert(&mut args, "name", "Alice");
+/// insert(&mut args, "count", OwnedValue(FluentValue::from(3)));
+///
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+/// ```
+pub trait IntoFluentValue {
+    /// Converts `self` into a [`FluentValue`].
+    fn into_fluent_value(self) -> Result<FluentValue<'static>, Error>;
+}
+
+impl<T> IntoFluentValue for T
+where
+    T: serde::Serialize,
+{
+    fn into_fluent_value(self) -> Result<FluentValue<'static>, Error> {
+        to_value(&self)
+    }
+}
+
+/// Returns `true` if `n` is exactly `v`. Float to integer casts saturate, so values
+/// rounded up past the maximum are checked separately.
+fn is_exact_signed(v: i128, n: f64) -> bool {
+    n < 2f64.powi(127) && n as i128 == v
+}
+
+/// Returns `true` if `n` is exactly `v`, as [`is_exact_signed`].
+fn is_exact_unsigned(v: u128, n: f64) -> bool {
+    n < 2f64.powi(128) && n as u128 == v
 }
 
 macro_rules! impl_cast_num {
     (
         $(
-            $f:ident ( $t:ident )
+            $f:ident ( $t:ident ) => $exact:ident
         ),*
         $(,)?
     ) => {
         $(
             fn $f (self, v: $t) -> Result<Self::Ok, Self::Error> {
-                Ok(FluentValue::Number(FluentNumber::new(v as f64, FluentNumberOptions::default())))
+                let n = v as f64;
+                if !impl_cast_num!(@exact $exact, v, n) {
+                    if self.options.is_strict_numbers() || self.options.is_strict() {
+                        return Err(Error::PrecisionLoss {
+                            value: v.to_string(),
+                            target: "f64",
+                        });
+                    }
+                    diag!(debug, "{} cannot be represented exactly, converted to {}", v, n);
+                    self.coerce(DataKind::Number)?;
+                }
+                Ok(self.number_value(n))
             }
         )*
     };
+    (@exact signed, $v:ident, $n:ident) => { is_exact_signed($v as i128, $n) };
+    (@exact unsigned, $v:ident, $n:ident) => { is_exact_unsigned($v as u128, $n) };
+    (@exact float, $v:ident, $n:ident) => { true };
 }
 
 impl Serializer for ValueSerializer {
@@ -76,37 +225,49 @@ impl Serializer for ValueSerializer {
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
         let num = if v { 1.0 } else { 0.0 };
-        Ok(FluentValue::Number(FluentNumber::new(
-            num,
-            FluentNumberOptions::default(),
-        )))
+        diag!(trace, "converting boolean {} to {}", v, num);
+        self.coerce(DataKind::Bool)?;
+        Ok(self.number_value(num))
     }
 
     impl_cast_num! {
-        serialize_i8(i8),
-        serialize_i16(i16),
-        serialize_i32(i32),
-        serialize_i64(i64),
-        serialize_i128(i128),
-        serialize_u8(u8),
-        serialize_u16(u16),
-        serialize_u32(u32),
-        serialize_u64(u64),
-        serialize_u128(u128),
-        serialize_f32(f32),
-        serialize_f64(f64),
+        serialize_i8(i8) => signed,
+        serialize_i16(i16) => signed,
+        serialize_i32(i32) => signed,
+        serialize_i64(i64) => signed,
+        serialize_i128(i128) => signed,
+        serialize_u8(u8) => unsigned,
+        serialize_u16(u16) => unsigned,
+        serialize_u32(u32) => unsigned,
+        serialize_u64(u64) => unsigned,
+        serialize_u128(u128) => unsigned,
+        serialize_f32(f32) => float,
+        serialize_f64(f64) => float,
     }
 
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
-        self.serialize_str(&v.to_string())
+        diag!(trace, "converting char {:?} to a string", v);
+        self.coerce(DataKind::Char)?;
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        Ok(FluentValue::String(Cow::Owned(v.to_string())))
+        Ok(self.string_value(|| Cow::Owned(v.to_string())))
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        // Formats straight into the owned value, rather than through a temporary
+        // string copied by `serialize_str`.
+        Ok(self.string_value(|| Cow::Owned(value.to_string())))
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        let s = std::str::from_utf8(v).map_err(|_| Error::NonUtf8Bytes)?;
+        let s = std::str::from_utf8(v).map_err(|err| Error::non_utf8_bytes(v, err))?;
+        diag!(trace, "converting {} bytes to a string", v.len());
+        self.coerce(DataKind::Bytes)?;
         self.serialize_str(s)
     }
 
@@ -119,94 +280,141 @@ impl Serializer for ValueSerializer {
     }
 
     fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
-        Ok(FluentValue::String(Cow::Borrowed(name)))
+        diag!(trace, "converting unit struct to its name `{}`", name);
+        self.coerce(DataKind::UnitStruct)?;
+        Ok(self.string_value(|| Cow::Borrowed(name)))
     }
 
     fn serialize_unit_variant(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variant_index: u32,
         variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        Ok(FluentValue::String(Cow::Borrowed(variant)))
+        if name == STATIC_NAME {
+            return Ok(self.string_value(|| Cow::Borrowed(variant)));
+        }
+        diag!(trace, "converting unit variant to its name `{}`", variant);
+        self.coerce(DataKind::UnitVariant)?;
+        Ok(self.string_value(|| Cow::Borrowed(variant)))
     }
 
-    fn serialize_some<T: ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self)
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(
+    fn serialize_newtype_struct<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
+        if name == RAW_NAME {
+            return value.serialize(ValueSerializer {
+                options: self.options.raw(),
+                ..self
+            });
+        }
         value.serialize(self)
     }
 
-    fn serialize_newtype_variant<T: ?Sized>(
+    fn serialize_newtype_variant<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variant_index: u32,
-        _variant: &'static str,
+        variant: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
+        let policy = self.options.result_policy();
+        if name == RESULT_NAME && variant == "Err" && policy != ResultPolicy::Inner {
+            let inner = value.serialize(ValueSerializer::new().kind_only(self.kind_only))?;
+            let message = if self.kind_only {
+                String::new()
+            } else {
+                render_value(&inner)
+            };
+            if policy == ResultPolicy::ErrorString {
+                return Ok(self.string_value(|| Cow::Owned(message)));
+            }
+            return Err(Error::ResultErr { message });
+        }
         value.serialize(self)
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Seq,
+            name: None,
+        })
     }
 
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Tuple,
+            name: None,
+        })
     }
 
     fn serialize_tuple_struct(
         self,
-        _name: &'static str,
+        name: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::TupleStruct,
+            name: Some(name),
+        })
     }
 
     fn serialize_struct(
         self,
-        _name: &'static str,
+        name: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Struct,
+            name: Some(name),
+        })
     }
 
     fn serialize_tuple_variant(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variant_index: u32,
         _variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::TupleVariant,
+            name: Some(name),
+        })
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::Map,
+            name: None,
+        })
     }
 
     fn serialize_struct_variant(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variant_index: u32,
         _variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        Err(Error::UnsupportedType)
+        Err(Error::UnsupportedType {
+            kind: DataKind::StructVariant,
+            name: Some(name),
+        })
     }
 }
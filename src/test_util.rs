@@ -0,0 +1,90 @@
+//! Assertions for tests comparing [`FluentArgs`]. Requires the `test-util`
+//! feature.
+
+use std::fmt::Write;
+
+use fluent::{FluentArgs, FluentValue};
+
+use crate::diff::diff;
+
+/// Asserts that a [`FluentArgs`] holds exactly the given arguments, printing the
+/// missing, extra, and mismatched arguments otherwise.
+///
+/// Expected arguments are given as with [`args!`](crate::args), and serialized with
+/// the default options. Values are compared by kind and value, so
+/// [`FluentValue::None`] and [`FluentValue::Error`] match themselves.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentArgs;
+/// use fluent_serde::assert_args_eq;
+///
+/// let mut args = FluentArgs::new();
+/// args.set("name", "Alice");
+/// args.set("count", 3);
+///
+/// assert_args_eq!(args, { "count" => 3, "name" => "Alice" });
+/// ```
+///
+/// A failing assertion panics with a message such as:
+///
+/// ```text
+/// arguments differ:
+///   mismatched `count`: expected number 4, found number 3
+///   missing `title`: expected string "Hello"
+///   extra `name`: string "Alice"
+/// ```
+#[macro_export]
+macro_rules! assert_args_eq {
+    ($actual:expr, { $($key:tt => $value:expr),* $(,)? } $(,)?) => {
+        $crate::test_util::assert_args_eq(
+            &$actual,
+            &$crate::args! { $($key => $value),* }
+                .expect("expected arguments failed to serialize"),
+        )
+    };
+}
+
+/// Asserts that `actual` and `expected` hold the same arguments, printing the
+/// missing, extra, and mismatched arguments otherwise. See [`assert_args_eq!`].
+#[track_caller]
+pub fn assert_args_eq(actual: &FluentArgs<'_>, expected: &FluentArgs<'_>) {
+    let diff = diff(expected, actual);
+    if diff.is_empty() {
+        return;
+    }
+
+    let mut message = String::new();
+    for (key, expected, actual) in diff.changed() {
+        let _ = writeln!(
+            message,
+            "  mismatched `{}`: expected {}, found {}",
+            key,
+            describe(expected),
+            describe(actual)
+        );
+    }
+    for (key, expected) in diff.removed() {
+        let _ = writeln!(
+            message,
+            "  missing `{}`: expected {}",
+            key,
+            describe(expected)
+        );
+    }
+    for (key, actual) in diff.added() {
+        let _ = writeln!(message, "  extra `{}`: {}", key, describe(actual));
+    }
+    panic!("arguments differ:\n{}", message);
+}
+
+fn describe(value: &FluentValue<'_>) -> String {
+    match value {
+        FluentValue::String(s) => format!("string {:?}", s),
+        FluentValue::Number(n) => format!("number {}", n.as_string()),
+        FluentValue::Custom(c) => format!("custom {:?}", c),
+        FluentValue::None => "none".to_string(),
+        FluentValue::Error => "error".to_string(),
+    }
+}
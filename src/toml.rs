@@ -0,0 +1,25 @@
+//! Helpers for loading [`FluentArgs`] from TOML, such as example argument sets in
+//! fixtures. Requires the `toml` feature.
+
+use fluent::FluentArgs;
+
+use crate::wrappers::OwnedArgs;
+
+/// Parses a TOML document of scalar values into a [`FluentArgs`].
+///
+/// Values are converted as by
+/// [`args_from_deserializer`](crate::de::args_from_deserializer). Tables, arrays,
+/// and date-times are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+///
+/// let args = fluent_serde::toml::args_from_str("name = \"Alice\"\ncount = 3").unwrap();
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+/// ```
+pub fn args_from_str(s: &str) -> Result<FluentArgs<'static>, ::toml::de::Error> {
+    ::toml::from_str::<OwnedArgs>(s).map(OwnedArgs::into_inner)
+}
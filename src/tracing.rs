@@ -0,0 +1,119 @@
+//! Recording of arguments in `tracing` spans. Requires the `tracing` feature.
+//!
+//! `tracing` requires span fields to be declared when the span is created, so the
+//! arguments are recorded together in a single field, as space-separated
+//! `name=value` pairs. The field must be declared on the span, e.g. with
+//! `fluent_args = tracing::field::Empty`.
+
+use std::fmt::{self, Write as _};
+
+use ::tracing::field;
+use ::tracing::Span;
+use fluent::{FluentArgs, FluentValue};
+
+/// Default name of the span field arguments are recorded in.
+pub const DEFAULT_FIELD: &str = "fluent_args";
+
+type RedactFn = dyn Fn(&str, &FluentValue) -> bool + Send + Sync;
+
+/// Records [`FluentArgs`] on spans, optionally redacting some values.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentArgs;
+/// use fluent_serde::tracing::ArgsRecorder;
+///
+/// let mut args = FluentArgs::new();
+/// args.set("user", "Alice");
+/// args.set("password", "hunter2");
+///
+/// let recorder = ArgsRecorder::new().redact(|key, _| key == "password");
+/// assert_eq!(r#"password=[redacted] user="Alice""#, recorder.format(&args));
+///
+/// let span = tracing::info_span!("render", fluent_args = tracing::field::Empty);
+/// recorder.record(&span, &args);
+/// ```
+pub struct ArgsRecorder {
+    field: &'static str,
+    redact: Option<Box<RedactFn>>,
+}
+
+impl Default for ArgsRecorder {
+    fn default() -> Self {
+        ArgsRecorder {
+            field: DEFAULT_FIELD,
+            redact: None,
+        }
+    }
+}
+
+impl fmt::Debug for ArgsRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArgsRecorder")
+            .field("field", &self.field)
+            .field("redact", &self.redact.is_some())
+            .finish()
+    }
+}
+
+impl ArgsRecorder {
+    /// Creates an [`ArgsRecorder`] that records into [`DEFAULT_FIELD`] without
+    /// redaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the span field to record into.
+    pub fn field(mut self, field: &'static str) -> Self {
+        self.field = field;
+        self
+    }
+
+    /// Sets a hook deciding whether the value of an argument should be redacted.
+    pub fn redact<F>(mut self, redact: F) -> Self
+    where
+        F: Fn(&str, &FluentValue) -> bool + Send + Sync + 'static,
+    {
+        self.redact = Some(Box::new(redact));
+        self
+    }
+
+    /// Records `args` on `span`. Does nothing if the span does not declare the field.
+    pub fn record(&self, span: &Span, args: &FluentArgs) {
+        span.record(self.field, field::display(self.format(args)));
+    }
+
+    /// Records `args` on the current span.
+    pub fn record_current(&self, args: &FluentArgs) {
+        self.record(&Span::current(), args);
+    }
+
+    /// Formats `args` the way they are recorded.
+    pub fn format(&self, args: &FluentArgs) -> String {
+        let mut out = String::new();
+        for (key, value) in args.iter() {
+            if !out.is_empty() {
+                out.push(' ');
+            }
+            out.push_str(key);
+            out.push('=');
+
+            let redacted = self.redact.as_ref().is_some_and(|f| f(key, value));
+            if redacted {
+                out.push_str("[redacted]");
+                continue;
+            }
+
+            match value {
+                FluentValue::String(s) => write!(out, "{:?}", s),
+                FluentValue::Number(n) => write!(out, "{}", n.value),
+                FluentValue::Custom(c) => write!(out, "{:?}", c),
+                FluentValue::None => write!(out, "none"),
+                FluentValue::Error => write!(out, "error"),
+            }
+            .unwrap();
+        }
+        out
+    }
+}
@@ -0,0 +1,100 @@
+//! Argument types bound to message identifiers.
+
+use std::borrow::Borrow;
+
+use fluent::{FluentBundle, FluentResource};
+use serde::Serialize;
+
+use crate::resolver::{format_message, Message, ResolveError};
+use crate::ser;
+
+/// An argument type bound to the identifier of the message it is for.
+///
+/// Structs generated with the `codegen` feature implement this trait.
+pub trait FluentMessage: Serialize {
+    /// Identifier of the message in the Fluent resources.
+    const ID: &'static str;
+
+    /// Wraps the arguments in a [`Message`], for use with a
+    /// [`Resolver`](crate::resolver::Resolver).
+    fn into_message(self) -> Message<Self>
+    where
+        Self: Sized,
+    {
+        Message::new(Self::ID, self)
+    }
+}
+
+/// Wraps a [`FluentBundle`] so that messages can only be formatted with the
+/// argument types bound to them.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::typed::{FluentMessage, TypedBundle};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Greeting {
+///     name: String,
+/// }
+///
+/// impl FluentMessage for Greeting {
+///     const ID: &'static str = "hello";
+/// }
+///
+/// let resource = FluentResource::try_new("hello = Hello, { $name }!".into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let bundle = TypedBundle::new(bundle);
+/// let text = bundle.format(&Greeting { name: "Alice".into() }).unwrap();
+/// assert_eq!("Hello, Alice!", text);
+/// ```
+pub struct TypedBundle<R = FluentResource> {
+    bundle: FluentBundle<R>,
+}
+
+impl<R> TypedBundle<R> {
+    /// Wraps `bundle`.
+    pub fn new(bundle: FluentBundle<R>) -> Self {
+        TypedBundle { bundle }
+    }
+
+    /// Returns the wrapped bundle.
+    pub fn bundle(&self) -> &FluentBundle<R> {
+        &self.bundle
+    }
+
+    /// Returns the wrapped bundle mutably, e.g. to add resources.
+    pub fn bundle_mut(&mut self) -> &mut FluentBundle<R> {
+        &mut self.bundle
+    }
+
+    /// Unwraps the bundle.
+    pub fn into_inner(self) -> FluentBundle<R> {
+        self.bundle
+    }
+}
+
+impl<R> TypedBundle<R>
+where
+    R: Borrow<FluentResource>,
+{
+    /// Formats the message bound to `T`, with arguments serialized from `message`.
+    pub fn format<T>(&self, message: &T) -> Result<String, ResolveError>
+    where
+        T: FluentMessage,
+    {
+        let args = || Ok(ser::to_args(message)?);
+        format_message(&self.bundle, T::ID, args, &mut Vec::new())
+    }
+}
+
+impl<R> From<FluentBundle<R>> for TypedBundle<R> {
+    fn from(bundle: FluentBundle<R>) -> Self {
+        Self::new(bundle)
+    }
+}
@@ -0,0 +1,259 @@
+//! Validation of serialized arguments against the variables messages reference,
+//! including re-validation of registered argument types when resources are reloaded.
+
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use fluent::bundle::FluentBundle;
+use fluent::FluentResource;
+use serde::Serialize;
+
+use crate::placeables::Placeables;
+use crate::ser;
+use crate::typed::FluentMessage;
+
+/// Differences between the arguments a value serializes into and the variables a
+/// message references.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Variables referenced by the message that are not produced by the value.
+    pub missing: Vec<String>,
+    /// Arguments produced by the value that the message never references.
+    pub unused: Vec<String>,
+    /// Other messages referenced by the message, directly or in turn. They are
+    /// formatted with the same arguments, so their variables are checked too.
+    pub references: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if there are no missing or unused arguments.
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.unused.is_empty()
+    }
+}
+
+/// Checks the arguments `args` serializes into against the variables referenced by
+/// the value and attributes of message `id` in `bundle`, and by the messages it
+/// references in turn, as collected by
+/// [`Placeables::from_message_with_references`]. Returns `None` if `bundle` has no
+/// message `id`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Cart {
+///     user: String,
+///     total: f64,
+/// }
+///
+/// let ftl = "
+/// user-name = { $user }
+/// cart = { user-name } has { $count } items.
+/// ";
+/// let resource = FluentResource::try_new(ftl.into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let cart = Cart { user: "Alice".into(), total: 3.0 };
+/// let report = fluent_serde::validate::check(&cart, &bundle, "cart").unwrap().unwrap();
+/// assert_eq!(vec!["count"], report.missing);
+/// assert_eq!(vec!["total"], report.unused);
+/// assert_eq!(vec!["user-name"], report.references);
+/// ```
+pub fn check<T, R, M>(
+    args: &T,
+    bundle: &FluentBundle<R, M>,
+    id: &str,
+) -> Result<Option<ValidationReport>, ser::Error>
+where
+    T: ?Sized + Serialize,
+    R: Borrow<FluentResource>,
+{
+    let args = ser::to_args(args)?;
+    let produced = args.iter().map(|(key, _)| key).collect::<BTreeSet<_>>();
+
+    Ok(compare(&produced, bundle, id))
+}
+
+fn compare<R, M>(
+    produced: &BTreeSet<&str>,
+    bundle: &FluentBundle<R, M>,
+    id: &str,
+) -> Option<ValidationReport>
+where
+    R: Borrow<FluentResource>,
+{
+    let message = bundle.get_message(id)?;
+    let placeables =
+        Placeables::from_message_with_references(&message, |id| bundle.get_message(id));
+    let referenced = placeables
+        .variables
+        .keys()
+        .copied()
+        .collect::<BTreeSet<_>>();
+    let references = placeables
+        .messages
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+
+    Some(ValidationReport {
+        missing: referenced
+            .difference(produced)
+            .map(|s| s.to_string())
+            .collect(),
+        unused: produced
+            .difference(&referenced)
+            .map(|s| s.to_string())
+            .collect(),
+        references,
+    })
+}
+
+/// Drift between registered argument types and the messages in a bundle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DriftReport {
+    /// Registered messages that do not exist in the bundle.
+    pub missing_messages: Vec<String>,
+    /// Reports for registered messages with missing or unused arguments, keyed by
+    /// message identifier.
+    pub messages: BTreeMap<String, ValidationReport>,
+}
+
+impl DriftReport {
+    /// Returns `true` if no drift was found.
+    pub fn is_empty(&self) -> bool {
+        self.missing_messages.is_empty() && self.messages.is_empty()
+    }
+}
+
+type DriftFn = dyn Fn(&DriftReport) + Send + Sync;
+
+/// Registry of argument types to re-validate whenever resources are reloaded.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::{FluentBundle, FluentResource};
+/// use fluent_serde::typed::FluentMessage;
+/// use fluent_serde::validate::Registry;
+/// use serde::Serialize;
+///
+/// #[derive(Default, Serialize)]
+/// struct Greeting {
+///     name: String,
+/// }
+///
+/// impl FluentMessage for Greeting {
+///     const ID: &'static str = "hello";
+/// }
+///
+/// let mut registry = Registry::new().on_drift(|report| {
+///     eprintln!("translations drifted: {:?}", report);
+/// });
+/// registry.register::<Greeting>().unwrap();
+///
+/// // After reloading resources...
+/// let resource = FluentResource::try_new("hello = Hello, { $user }!".into()).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+/// bundle.add_resource(resource).unwrap();
+///
+/// let report = registry.reload(&bundle);
+/// assert_eq!(vec!["user"], report.messages["hello"].missing);
+/// assert_eq!(vec!["name"], report.messages["hello"].unused);
+/// ```
+#[derive(Default)]
+pub struct Registry {
+    entries: BTreeMap<String, BTreeSet<String>>,
+    on_drift: Option<Box<DriftFn>>,
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field("entries", &self.entries)
+            .field("on_drift", &self.on_drift.is_some())
+            .finish()
+    }
+}
+
+impl Registry {
+    /// Creates an empty [`Registry`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a callback invoked by [`Registry::reload`] when drift is found.
+    pub fn on_drift<F>(mut self, on_drift: F) -> Self
+    where
+        F: Fn(&DriftReport) + Send + Sync + 'static,
+    {
+        self.on_drift = Some(Box::new(on_drift));
+        self
+    }
+
+    /// Registers the arguments the [`Default`] value of `M` serializes into as the
+    /// arguments of message [`M::ID`](FluentMessage::ID), replacing any previous
+    /// registration.
+    pub fn register<M>(&mut self) -> Result<(), ser::Error>
+    where
+        M: FluentMessage + Default,
+    {
+        self.register_args(M::ID, &M::default())
+    }
+
+    /// Registers the arguments `args` serializes into as the arguments of message
+    /// `id`, replacing any previous registration. A representative value, such as
+    /// the [`Default`] of the argument type, should be used. Prefer
+    /// [`register`](Self::register) for types implementing [`FluentMessage`].
+    pub fn register_args<T>(&mut self, id: impl Into<String>, args: &T) -> Result<(), ser::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let names = ser::to_args(args)?
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .collect();
+        self.entries.insert(id.into(), names);
+        Ok(())
+    }
+
+    /// Validates all registered messages against `bundle`.
+    pub fn check<R, M>(&self, bundle: &FluentBundle<R, M>) -> DriftReport
+    where
+        R: Borrow<FluentResource>,
+    {
+        let mut report = DriftReport::default();
+        for (id, names) in &self.entries {
+            let produced = names.iter().map(String::as_str).collect();
+            match compare(&produced, bundle, id) {
+                Some(validation) if !validation.is_ok() => {
+                    report.messages.insert(id.clone(), validation);
+                }
+                Some(_) => {}
+                None => report.missing_messages.push(id.clone()),
+            }
+        }
+        report
+    }
+
+    /// Validates all registered messages against a reloaded `bundle`, invoking the
+    /// drift callback if any drift is found.
+    pub fn reload<R, M>(&self, bundle: &FluentBundle<R, M>) -> DriftReport
+    where
+        R: Borrow<FluentResource>,
+    {
+        let report = self.check(bundle);
+        if !report.is_empty() {
+            if let Some(on_drift) = &self.on_drift {
+                on_drift(&report);
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,68 @@
+//! Helpers for WebAssembly frontends. Requires the `wasm` feature.
+
+use fluent::{FluentArgs, FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+use crate::de::args_from_deserializer;
+use crate::resolver::format_message;
+
+/// Builds a [`FluentArgs`] from a plain JavaScript object.
+///
+/// Values are converted as by [`args_from_deserializer`].
+pub fn args_from_js(value: &JsValue) -> Result<FluentArgs<'static>, serde_wasm_bindgen::Error> {
+    args_from_deserializer(serde_wasm_bindgen::Deserializer::from(value.clone()))
+}
+
+/// A [`FluentBundle`] exported to JavaScript as `Bundle`, so that web apps can
+/// format messages with arguments from JavaScript objects.
+///
+/// ```js
+/// const bundle = new Bundle("en");
+/// bundle.addResource("hello = Hello, { $name }!");
+/// bundle.format("hello", { name: "Alice" });
+/// ```
+///
+/// Errors are thrown as strings.
+#[wasm_bindgen(js_name = Bundle)]
+pub struct JsBundle {
+    bundle: FluentBundle<FluentResource>,
+}
+
+#[wasm_bindgen(js_class = Bundle)]
+impl JsBundle {
+    /// Creates an empty bundle for `locale`, a BCP 47 language tag.
+    #[wasm_bindgen(constructor)]
+    pub fn new(locale: &str) -> Result<JsBundle, JsValue> {
+        let locale: LanguageIdentifier = locale
+            .parse()
+            .map_err(|err| JsValue::from_str(&format!("invalid locale `{}`: {}", locale, err)))?;
+        Ok(JsBundle {
+            bundle: FluentBundle::new(vec![locale]),
+        })
+    }
+
+    /// Sets whether placeables are wrapped in Unicode isolation marks.
+    #[wasm_bindgen(js_name = setUseIsolating)]
+    pub fn set_use_isolating(&mut self, value: bool) {
+        self.bundle.set_use_isolating(value);
+    }
+
+    /// Parses `source` as FTL and adds its messages to the bundle.
+    #[wasm_bindgen(js_name = addResource)]
+    pub fn add_resource(&mut self, source: String) -> Result<(), JsValue> {
+        let resource = FluentResource::try_new(source)
+            .map_err(|(_, errors)| JsValue::from_str(&format!("{:?}", errors)))?;
+        self.bundle
+            .add_resource(resource)
+            .map_err(|errors| JsValue::from_str(&format!("{:?}", errors)))
+    }
+
+    /// Formats the message `id` with the arguments in `args`, a plain object.
+    pub fn format(&self, id: &str, args: JsValue) -> Result<String, JsValue> {
+        let args = args_from_js(&args)?;
+        format_message(&self.bundle, id, move || Ok(args), &mut Vec::new())
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+}
@@ -0,0 +1,104 @@
+//! Helpers for web applications. Requires the `web` feature.
+//!
+//! With the `axum` feature, [`LocalizedArgs`] extracts typed arguments from the
+//! query string of a request.
+
+use fluent::FluentArgs;
+
+use crate::wrappers::OwnedArgs;
+
+/// Builds a [`FluentArgs`] from `application/x-www-form-urlencoded` data, such as
+/// a query string or a form body. All values are strings.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+///
+/// let args = fluent_serde::web::args_from_urlencoded("name=Alice&count=3").unwrap();
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// assert_eq!(Some(&FluentValue::from("3")), args.get("count"));
+/// ```
+pub fn args_from_urlencoded(
+    input: &str,
+) -> Result<FluentArgs<'static>, serde_urlencoded::de::Error> {
+    serde_urlencoded::from_str::<OwnedArgs>(input).map(OwnedArgs::into_inner)
+}
+
+#[cfg(feature = "axum")]
+pub use self::extract::{LocalizedArgs, LocalizedArgsRejection};
+
+#[cfg(feature = "axum")]
+mod extract {
+    use ::axum::extract::FromRequestParts;
+    use ::axum::http::request::Parts;
+    use ::axum::http::StatusCode;
+    use ::axum::response::{IntoResponse, Response};
+    use fluent::FluentArgs;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use thiserror::Error;
+
+    use crate::ser;
+
+    /// Extracts the query string of a request as a typed value, along with the
+    /// arguments it serializes into.
+    ///
+    /// ```rust,no_run
+    /// use fluent_serde::web::LocalizedArgs;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Deserialize, Serialize)]
+    /// struct Search {
+    ///     query: String,
+    ///     page: u32,
+    /// }
+    ///
+    /// async fn search(LocalizedArgs { value, args }: LocalizedArgs<Search>) -> String {
+    ///     // Format a message with `args`...
+    ///     # let _ = (value, args);
+    ///     String::new()
+    /// }
+    /// ```
+    pub struct LocalizedArgs<T> {
+        /// The deserialized query.
+        pub value: T,
+        /// Arguments serialized from `value`.
+        pub args: FluentArgs<'static>,
+    }
+
+    /// Rejection used for [`LocalizedArgs`].
+    #[derive(Debug, Error)]
+    #[non_exhaustive]
+    pub enum LocalizedArgsRejection {
+        #[error("failed to deserialize query string: {0}")]
+        Query(#[from] serde_urlencoded::de::Error),
+        #[error("failed to serialize arguments: {0}")]
+        Args(#[from] ser::Error),
+    }
+
+    impl IntoResponse for LocalizedArgsRejection {
+        fn into_response(self) -> Response {
+            (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+        }
+    }
+
+    #[::axum::async_trait]
+    impl<T, S> FromRequestParts<S> for LocalizedArgs<T>
+    where
+        T: DeserializeOwned + Serialize + Send,
+        S: Send + Sync,
+    {
+        type Rejection = LocalizedArgsRejection;
+
+        async fn from_request_parts(
+            parts: &mut Parts,
+            _state: &S,
+        ) -> Result<Self, Self::Rejection> {
+            let value: T = serde_urlencoded::from_str(parts.uri.query().unwrap_or_default())?;
+
+            let args = ser::to_args(&value)?;
+            Ok(LocalizedArgs { value, args })
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Wrappers implementing `serde` traits for `fluent` types.
+
+use fluent::{FluentArgs, FluentValue};
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::de::args_from_deserializer;
+use crate::ser::{self, IntoFluentValue, ToFluentArgs};
+
+/// Serializes a [`FluentArgs`] as a map from argument names to values.
+///
+/// Values are represented as described in [`SerializableValue`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentArgs;
+/// use fluent_serde::wrappers::SerializableArgs;
+/// use serde::Serialize;
+///
+/// let mut args = FluentArgs::new();
+/// args.set("name", "Alice");
+/// args.set("count", 3);
+///
+/// let mut ser = fluent_serde::ArgsSerializer::new();
+/// SerializableArgs(&args).serialize(&mut ser).unwrap();
+/// let copy = ser.done();
+///
+/// assert_eq!(args.get("name"), copy.get("name"));
+/// assert_eq!(args.get("count"), copy.get("count"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SerializableArgs<'a>(pub &'a FluentArgs<'a>);
+
+impl<'a> Serialize for SerializableArgs<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.iter().count()))?;
+        for (key, value) in self.0.iter() {
+            map.serialize_entry(key, &SerializableValue(value))?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes a [`FluentValue`].
+///
+/// The representation is:
+///
+/// - [`FluentValue::String`] as a string.
+/// - [`FluentValue::Number`] as an [`f64`]. Formatting options are not serialized.
+/// - [`FluentValue::None`] and [`FluentValue::Error`] as a unit value, e.g. `null` in
+///   JSON. Both format as empty strings in a bundle.
+/// - [`FluentValue::Custom`] is not supported, because custom types can only be
+///   formatted with a bundle's memoizer. Serializing one produces an error.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::wrappers::SerializableValue;
+/// use fluent_serde::ValueSerializer;
+/// use serde::Serialize;
+///
+/// let value = FluentValue::from(42);
+/// let copy = SerializableValue(&value)
+///     .serialize(ValueSerializer::new())
+///     .unwrap();
+/// assert_eq!(value, copy);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SerializableValue<'a>(pub &'a FluentValue<'a>);
+
+impl<'a> Serialize for SerializableValue<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            FluentValue::String(s) => serializer.serialize_str(s),
+            FluentValue::Number(n) => serializer.serialize_f64(n.value),
+            FluentValue::Custom(_) => Err(S::Error::custom(
+                "custom Fluent values cannot be serialized",
+            )),
+            FluentValue::None | FluentValue::Error => serializer.serialize_unit(),
+        }
+    }
+}
+
+/// Deserializes a [`FluentArgs`] from a map from argument names to values.
+///
+/// This reads back the output of [`SerializableArgs`]. Values round trip as
+/// described in [`SerializableValue`], except that [`FluentValue::Error`] is read
+/// back as [`FluentValue::None`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::wrappers::OwnedArgs;
+/// use serde::de::value::{Error, MapDeserializer};
+/// use serde::Deserialize;
+///
+/// let entries = vec![("name", "Alice")];
+/// let deserializer = MapDeserializer::<_, Error>::new(entries.into_iter());
+/// let args = OwnedArgs::deserialize(deserializer).unwrap().into_inner();
+///
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// ```
+#[derive(Debug, Default)]
+pub struct OwnedArgs(pub FluentArgs<'static>);
+
+impl OwnedArgs {
+    /// Returns the wrapped [`FluentArgs`].
+    pub fn into_inner(self) -> FluentArgs<'static> {
+        self.0
+    }
+}
+
+impl From<OwnedArgs> for FluentArgs<'static> {
+    fn from(args: OwnedArgs) -> Self {
+        args.0
+    }
+}
+
+impl ToFluentArgs for OwnedArgs {
+    fn to_fluent_args(&self) -> Result<FluentArgs<'static>, ser::Error> {
+        Ok(self
+            .0
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.into_owned()))
+            .collect())
+    }
+}
+
+impl<'de> Deserialize<'de> for OwnedArgs {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        args_from_deserializer(deserializer).map(OwnedArgs)
+    }
+}
+
+/// An owned [`FluentValue`], passed through as it is where an
+/// [`IntoFluentValue`] is expected.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+/// use fluent_serde::wrappers::OwnedValue;
+/// use fluent_serde::IntoFluentValue;
+///
+/// let value = OwnedValue(FluentValue::from(42)).into_fluent_value().unwrap();
+/// assert_eq!(FluentValue::from(42), value);
+/// ```
+#[derive(Debug)]
+pub struct OwnedValue(pub FluentValue<'static>);
+
+impl OwnedValue {
+    /// Returns the wrapped [`FluentValue`].
+    pub fn into_inner(self) -> FluentValue<'static> {
+        self.0
+    }
+}
+
+impl From<FluentValue<'static>> for OwnedValue {
+    fn from(value: FluentValue<'static>) -> Self {
+        OwnedValue(value)
+    }
+}
+
+impl IntoFluentValue for OwnedValue {
+    fn into_fluent_value(self) -> Result<FluentValue<'static>, ser::Error> {
+        Ok(self.0)
+    }
+}
@@ -0,0 +1,55 @@
+//! Helpers for loading [`FluentArgs`] from YAML, such as QA fixtures. Requires the
+//! `yaml` feature.
+
+use std::collections::BTreeMap;
+
+use fluent::FluentArgs;
+
+use crate::wrappers::OwnedArgs;
+
+/// Parses a YAML mapping of scalar values into a [`FluentArgs`].
+///
+/// Values are converted as by
+/// [`args_from_deserializer`](crate::de::args_from_deserializer). Nested mappings
+/// and sequences are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+///
+/// let args = fluent_serde::yaml::args_from_str("name: Alice\ncount: 3").unwrap();
+/// assert_eq!(Some(&FluentValue::from("Alice")), args.get("name"));
+/// assert_eq!(Some(&FluentValue::from(3)), args.get("count"));
+/// ```
+pub fn args_from_str(s: &str) -> Result<FluentArgs<'static>, serde_yaml::Error> {
+    serde_yaml::from_str::<OwnedArgs>(s).map(OwnedArgs::into_inner)
+}
+
+/// Parses a YAML mapping from message identifiers to mappings of scalar values,
+/// into the arguments for each message.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent::FluentValue;
+///
+/// let yaml = "
+/// greeting:
+///   name: Alice
+/// cart:
+///   count: 3
+/// ";
+/// let fixtures = fluent_serde::yaml::fixtures_from_str(yaml).unwrap();
+/// assert_eq!(Some(&FluentValue::from("Alice")), fixtures["greeting"].get("name"));
+/// assert_eq!(Some(&FluentValue::from(3)), fixtures["cart"].get("count"));
+/// ```
+pub fn fixtures_from_str(
+    s: &str,
+) -> Result<BTreeMap<String, FluentArgs<'static>>, serde_yaml::Error> {
+    let fixtures: BTreeMap<String, OwnedArgs> = serde_yaml::from_str(s)?;
+    Ok(fixtures
+        .into_iter()
+        .map(|(id, args)| (id, args.into_inner()))
+        .collect())
+}